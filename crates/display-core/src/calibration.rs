@@ -0,0 +1,140 @@
+//! Output color calibration. Cheap panels disagree about color — one
+//! tints blue, another crushes its darks — so the same orange comes
+//! out three different ways. A [`Calibration`] corrects one panel:
+//! per-channel gain (white balance), per-channel gamma, and a black
+//! level floor under the dimmest lit values.
+//!
+//! Calibration describes the hardware, not the scene, so it isn't
+//! part of [`crate::render`]: the driver applies it to each finished
+//! frame on its way to the sink, and the simulator leaves it out.
+//! The test pattern [`crate::test::TestPattern::Calibration`] is the
+//! chart to tune it against by eye.
+
+use embedded_graphics::{pixelcolor::Rgb888, prelude::*};
+use serde::{Deserialize, Serialize};
+
+/// One number per color channel. Deserializes from either a table
+/// (`{ r = 1.0, g = 0.9, b = 0.8 }`) or a single number for all
+/// three.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(from = "ChannelsRepr")]
+pub struct Channels {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+
+impl Channels {
+    #[must_use]
+    pub const fn all(value: f32) -> Self {
+        Self {
+            r: value,
+            g: value,
+            b: value,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ChannelsRepr {
+    All(f32),
+    Each { r: f32, g: f32, b: f32 },
+}
+
+impl From<ChannelsRepr> for Channels {
+    fn from(repr: ChannelsRepr) -> Self {
+        match repr {
+            ChannelsRepr::All(value) => Self::all(value),
+            ChannelsRepr::Each { r, g, b } => Self { r, g, b },
+        }
+    }
+}
+
+/// Per-panel color correction. Each channel goes through
+/// `gain × (value / 255)^gamma`, then the lit range is lifted onto
+/// `black_level..=255`. The default changes nothing.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Calibration {
+    /// Channel multipliers in [0, 1]: pull down whichever channel the
+    /// panel overdrives to fix its white balance.
+    #[serde(default = "unity")]
+    pub gain: Channels,
+    /// Channel exponents. 1.0 is linear; ~2.2 makes fades and
+    /// gradients step evenly to the eye.
+    #[serde(default = "unity")]
+    pub gamma: Channels,
+    /// Lowest output for a lit channel. Values the panel's PWM can't
+    /// show start here instead of vanishing; black stays black.
+    #[serde(default)]
+    pub black_level: u8,
+}
+
+fn unity() -> Channels {
+    Channels::all(1.0)
+}
+
+impl Default for Calibration {
+    fn default() -> Self {
+        Self {
+            gain: unity(),
+            gamma: unity(),
+            black_level: 0,
+        }
+    }
+}
+
+impl Calibration {
+    /// True when [`CalibrationLut::apply`] would change nothing.
+    #[must_use]
+    pub fn is_identity(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Bake into lookup tables, so correcting a frame is three
+    /// indexes per pixel.
+    #[must_use]
+    pub fn lut(&self) -> CalibrationLut {
+        CalibrationLut {
+            r: self.curve(self.gain.r, self.gamma.r),
+            g: self.curve(self.gain.g, self.gamma.g),
+            b: self.curve(self.gain.b, self.gamma.b),
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    fn curve(&self, gain: f32, gamma: f32) -> [u8; 256] {
+        let gain = gain.clamp(0.0, 1.0);
+        let gamma = if gamma > 0.0 { gamma } else { 1.0 };
+        let floor = f32::from(self.black_level);
+        let mut table = [0; 256];
+        for (value, out) in (0u8..=255).zip(table.iter_mut()) {
+            if value == 0 || gain == 0.0 {
+                continue;
+            }
+            let level = gain * (f32::from(value) / 255.0).powf(gamma);
+            *out = (floor + level * (255.0 - floor)).round().clamp(1.0, 255.0) as u8;
+        }
+        table
+    }
+}
+
+/// A [`Calibration`] baked into one table per channel.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CalibrationLut {
+    pub r: [u8; 256],
+    pub g: [u8; 256],
+    pub b: [u8; 256],
+}
+
+impl CalibrationLut {
+    #[must_use]
+    pub fn apply(&self, color: Rgb888) -> Rgb888 {
+        Rgb888::new(
+            self.r[usize::from(color.r())],
+            self.g[usize::from(color.g())],
+            self.b[usize::from(color.b())],
+        )
+    }
+}
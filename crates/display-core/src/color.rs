@@ -0,0 +1,171 @@
+//! Colors that vary across the canvas or over time, shared by the
+//! text, clock and life renderers.
+//!
+//! [`TextEntryColor`](crate::text::TextEntryColor) carries these next
+//! to plain RGB and rainbow; clock and life take one as an optional
+//! `fill` that overrides their solid color. Everything is a pure
+//! function of position and `step`, so the simulator and the panel
+//! agree pixel for pixel.
+
+use embedded_graphics::{pixelcolor::Rgb888, prelude::*, primitives::Rectangle, Pixel};
+use serde::{Deserialize, Serialize};
+
+use crate::text::Rgb;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum GradientDirection {
+    /// First stop on the left.
+    #[default]
+    Horizontal,
+    /// First stop at the top.
+    Vertical,
+}
+
+/// Linear blend through `stops`, spread across whatever is being
+/// drawn (a line of text, the clock readout, the whole life canvas).
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GradientOptions {
+    pub stops: Vec<Rgb>,
+    #[serde(default)]
+    pub direction: GradientDirection,
+    /// Scroll the gradient along its axis, same units as the marquee
+    /// (`speed / 10` pixels per step). While moving, the last stop
+    /// blends back into the first so the wrap is seamless. 0 = still.
+    #[serde(default)]
+    pub speed: u32,
+}
+
+/// Step through `colors` in order, one every `steps_per_color`,
+/// wrapping round at the end.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PaletteOptions {
+    pub colors: Vec<Rgb>,
+    pub steps_per_color: usize,
+    /// Fade into the next color over each slot instead of cutting.
+    #[serde(default)]
+    pub blend: bool,
+}
+
+/// Breathe from `from` to `to` and back once every `period_steps`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PulseOptions {
+    pub from: Rgb,
+    pub to: Rgb,
+    pub period_steps: usize,
+}
+
+impl GradientOptions {
+    /// Color at `point` of a shape spanning `bounds`.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn at(&self, point: Point, bounds: Rectangle, step: usize) -> Rgb888 {
+        let (offset, extent) = match self.direction {
+            GradientDirection::Horizontal => {
+                (point.x - bounds.top_left.x, bounds.size.width as i32)
+            }
+            GradientDirection::Vertical => (point.y - bounds.top_left.y, bounds.size.height as i32),
+        };
+        if self.speed == 0 {
+            let t = offset as f32 / (extent - 1).max(1) as f32;
+            return ramp(&self.stops, t, false);
+        }
+        let extent = extent.max(1);
+        let shift = (step as u64 * u64::from(self.speed) / 10 % extent as u64) as i32;
+        let t = (offset - shift).rem_euclid(extent) as f32 / extent as f32;
+        ramp(&self.stops, t, true)
+    }
+}
+
+impl PaletteOptions {
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn at(&self, step: usize) -> Rgb888 {
+        if self.colors.is_empty() {
+            return Rgb888::BLACK;
+        }
+        let slot = self.steps_per_color.max(1);
+        let index = step / slot % self.colors.len();
+        let current = self.colors[index];
+        if !self.blend {
+            return current.into();
+        }
+        let next = self.colors[(index + 1) % self.colors.len()];
+        lerp(current, next, (step % slot) as f32 / slot as f32)
+    }
+}
+
+impl PulseOptions {
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn at(&self, step: usize) -> Rgb888 {
+        if self.period_steps == 0 {
+            return self.from.into();
+        }
+        let phase = (step % self.period_steps) as f32 / self.period_steps as f32;
+        // Cosine ease: rests briefly at each end instead of bouncing.
+        let t = (1.0 - (phase * std::f32::consts::TAU).cos()) / 2.0;
+        lerp(self.from, self.to, t)
+    }
+}
+
+/// Blend along `stops` at `t` in [0, 1]. A `cyclic` ramp has one more
+/// segment, from the last stop back to the first.
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_precision_loss)]
+#[allow(clippy::cast_sign_loss)]
+fn ramp(stops: &[Rgb], t: f32, cyclic: bool) -> Rgb888 {
+    match stops {
+        [] => Rgb888::BLACK,
+        [only] => (*only).into(),
+        _ => {
+            let segments = if cyclic { stops.len() } else { stops.len() - 1 };
+            let position = t.clamp(0.0, 1.0) * segments as f32;
+            let index = (position as usize).min(segments - 1);
+            let next = stops[(index + 1) % stops.len()];
+            lerp(stops[index], next, position - index as f32)
+        }
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_sign_loss)]
+fn lerp(a: Rgb, b: Rgb, t: f32) -> Rgb888 {
+    let mix = |a: u8, b: u8| (f32::from(a) + (f32::from(b) - f32::from(a)) * t).round() as u8;
+    Rgb888::new(mix(a.r, b.r), mix(a.g, b.g), mix(a.b, b.b))
+}
+
+/// `DrawTarget` wrapper that repaints every pixel drawn through it
+/// with `paint(point)`, whatever color it was drawn in. Lets glyphs
+/// and cells be drawn once and take a per-pixel gradient.
+pub(crate) struct Recolor<'a, D, F> {
+    pub(crate) inner: &'a mut D,
+    pub(crate) paint: F,
+}
+
+impl<D, F> DrawTarget for Recolor<'_, D, F>
+where
+    D: DrawTarget<Color = Rgb888>,
+    F: Fn(Point) -> Rgb888,
+{
+    type Color = Rgb888;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Rgb888>>,
+    {
+        let paint = &self.paint;
+        self.inner
+            .draw_iter(pixels.into_iter().map(|Pixel(p, _)| Pixel(p, paint(p))))
+    }
+}
+
+impl<D, F> Dimensions for Recolor<'_, D, F>
+where
+    D: Dimensions,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.inner.bounding_box()
+    }
+}
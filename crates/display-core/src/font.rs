@@ -0,0 +1,748 @@
+//! Fonts for the text-drawing modes.
+//!
+//! [`FontId`] is the persisted selection: one of the built-in
+//! embedded-graphics mono fonts, or a BDF font by name. Renderers
+//! resolve it to a [`Font`] each frame and lay glyphs out from the
+//! top of each line, so entries in different sizes stack cleanly.
+//!
+//! BDF fonts are parsed with [`BdfFont::parse`] and made available to
+//! every renderer through [`register_bdf`]. The driver loads its
+//! configured font directory at startup; an unknown name falls back
+//! to the default 5×8 font rather than blanking the line. Compiled
+//! PCF fonts load the same way through [`BdfFont::parse_pcf`].
+//!
+//! Text is full UTF-8. Each character is drawn from the first face
+//! in a fallback chain that has a glyph for it:
+//!
+//! 1. the selected font — for the built-in sizes that's Latin-1, then
+//!    the other ISO 8859 sets (Latin Extended, Cyrillic, Greek) and,
+//!    at 6×13 and up, halfwidth katakana;
+//! 2. for BDF fonts, the built-in set nearest their line height;
+//! 3. a small built-in set of common symbols (arrows, dashes, ♥, ★, ✓);
+//! 4. the BDF fonts named in [`set_fallbacks`], e.g. a Unifont build
+//!    for CJK.
+//!
+//! Characters no face covers draw as the selected font's replacement
+//! glyph, with the same advance as everything else measures them at.
+//! Fallback glyphs share the line's baseline.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, OnceLock, PoisonError, RwLock};
+
+use embedded_graphics::{
+    mono_font::{
+        iso_8859_1, iso_8859_10, iso_8859_13, iso_8859_14, iso_8859_15, iso_8859_16, iso_8859_2,
+        iso_8859_3, iso_8859_4, iso_8859_5, iso_8859_7, iso_8859_9, jis_x0201,
+        mapping::{self, StrGlyphMapping},
+        MonoFont, MonoTextStyle,
+    },
+    pixelcolor::Rgb888,
+    prelude::*,
+    text::{Baseline, Text},
+    Pixel,
+};
+use serde::{Deserialize, Serialize};
+
+/// Persisted font selection.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default, Deserialize, Serialize)]
+pub enum FontId {
+    #[serde(rename = "4x6")]
+    Font4x6,
+    #[serde(rename = "5x7")]
+    Font5x7,
+    #[default]
+    #[serde(rename = "5x8")]
+    Font5x8,
+    #[serde(rename = "6x10")]
+    Font6x10,
+    #[serde(rename = "6x13")]
+    Font6x13,
+    #[serde(rename = "9x15")]
+    Font9x15,
+    /// Large headline / digit font — three lines fill a 64px panel.
+    #[serde(rename = "10x20")]
+    Font10x20,
+    /// A BDF font registered under this name.
+    Bdf(String),
+}
+
+/// One embedded-graphics mono font and the characters it covers.
+type MonoFace = (
+    &'static MonoFont<'static>,
+    &'static StrGlyphMapping<'static>,
+);
+
+/// Every ISO 8859 variant of one mono font size, Latin-1 first.
+macro_rules! mono_chain {
+    ($font:ident $(, $extra:ident => $extra_mapping:ident)*) => {
+        &[
+            (&iso_8859_1::$font, &mapping::ISO_8859_1),
+            (&iso_8859_15::$font, &mapping::ISO_8859_15),
+            (&iso_8859_2::$font, &mapping::ISO_8859_2),
+            (&iso_8859_3::$font, &mapping::ISO_8859_3),
+            (&iso_8859_4::$font, &mapping::ISO_8859_4),
+            (&iso_8859_9::$font, &mapping::ISO_8859_9),
+            (&iso_8859_10::$font, &mapping::ISO_8859_10),
+            (&iso_8859_13::$font, &mapping::ISO_8859_13),
+            (&iso_8859_14::$font, &mapping::ISO_8859_14),
+            (&iso_8859_16::$font, &mapping::ISO_8859_16),
+            (&iso_8859_5::$font, &mapping::ISO_8859_5),
+            (&iso_8859_7::$font, &mapping::ISO_8859_7),
+            $((&$extra::$font, &mapping::$extra_mapping),)*
+        ]
+    };
+}
+
+static MONO_4X6: &[MonoFace] = mono_chain!(FONT_4X6);
+static MONO_5X7: &[MonoFace] = mono_chain!(FONT_5X7);
+static MONO_5X8: &[MonoFace] = mono_chain!(FONT_5X8);
+static MONO_6X10: &[MonoFace] = mono_chain!(FONT_6X10);
+static MONO_6X13: &[MonoFace] = mono_chain!(FONT_6X13, jis_x0201 => JIS_X0201);
+static MONO_9X15: &[MonoFace] = mono_chain!(FONT_9X15, jis_x0201 => JIS_X0201);
+static MONO_10X20: &[MonoFace] = mono_chain!(FONT_10X20, jis_x0201 => JIS_X0201);
+
+/// A [`FontId`] resolved for drawing, with its fallback chain.
+#[derive(Clone)]
+pub struct Font {
+    primary: Face,
+    /// Tried in order for characters `primary` has no glyph for.
+    fallbacks: Vec<Face>,
+}
+
+#[derive(Clone)]
+enum Face {
+    Mono(&'static [MonoFace]),
+    Bdf(Arc<BdfFont>),
+}
+
+/// Where one character's pixels come from.
+enum Glyph<'a> {
+    Mono(&'static MonoFont<'static>),
+    Bdf(&'a BdfGlyph),
+    /// Nothing to draw; just advance.
+    Blank(i32),
+}
+
+impl Font {
+    /// Resolve `id`. Unregistered BDF names fall back to the default
+    /// font.
+    #[must_use]
+    pub fn resolve(id: &FontId) -> Self {
+        let primary = match id {
+            FontId::Font4x6 => Face::Mono(MONO_4X6),
+            FontId::Font5x7 => Face::Mono(MONO_5X7),
+            FontId::Font5x8 => Face::Mono(MONO_5X8),
+            FontId::Font6x10 => Face::Mono(MONO_6X10),
+            FontId::Font6x13 => Face::Mono(MONO_6X13),
+            FontId::Font9x15 => Face::Mono(MONO_9X15),
+            FontId::Font10x20 => Face::Mono(MONO_10X20),
+            FontId::Bdf(name) => lookup_bdf(name).map_or(Face::Mono(MONO_5X8), Face::Bdf),
+        };
+        let mut fallbacks = Vec::new();
+        if let Face::Bdf(font) = &primary {
+            fallbacks.push(Face::Mono(mono_for_height(font.ascent + font.descent)));
+        }
+        fallbacks.push(Face::Bdf(symbols()));
+        fallbacks.extend(
+            fallback_names()
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .iter()
+                .filter_map(|name| lookup_bdf(name))
+                .map(Face::Bdf),
+        );
+        Self { primary, fallbacks }
+    }
+
+    /// Height of one line of text, without inter-line spacing.
+    #[must_use]
+    pub fn line_height(&self) -> i32 {
+        self.primary.line_height()
+    }
+
+    /// Horizontal advance of `c`, including any inter-character
+    /// spacing.
+    #[must_use]
+    pub fn char_width(&self, c: char) -> i32 {
+        self.glyph(c).advance()
+    }
+
+    /// Total advance of `text`.
+    #[must_use]
+    pub fn text_width(&self, text: &str) -> i32 {
+        text.chars().map(|c| self.char_width(c)).sum()
+    }
+
+    /// Draw `text` with its line box's top-left corner at `top_left`.
+    /// Returns the advance, i.e. where the next glyph would start.
+    pub fn draw<D>(
+        &self,
+        text: &str,
+        top_left: Point,
+        color: Rgb888,
+        canvas: &mut D,
+    ) -> Result<i32, D::Error>
+    where
+        D: DrawTarget<Color = Rgb888>,
+    {
+        let baseline = top_left.y + self.primary.ascent();
+        let mut x = top_left.x;
+        for c in text.chars() {
+            x += self
+                .glyph(c)
+                .draw(c, Point::new(x, baseline), color, canvas)?;
+        }
+        Ok(x - top_left.x)
+    }
+
+    fn glyph(&self, c: char) -> Glyph<'_> {
+        if is_zero_width(c) {
+            return Glyph::Blank(0);
+        }
+        std::iter::once(&self.primary)
+            .chain(&self.fallbacks)
+            .find_map(|face| face.glyph(c))
+            .unwrap_or_else(|| self.primary.replacement())
+    }
+}
+
+impl Face {
+    #[allow(clippy::cast_possible_wrap)]
+    fn line_height(&self) -> i32 {
+        match self {
+            Self::Mono(chain) => chain[0].0.character_size.height as i32,
+            Self::Bdf(font) => font.ascent + font.descent,
+        }
+    }
+
+    /// Rows from the top of the line box down to and including the
+    /// baseline row.
+    #[allow(clippy::cast_possible_wrap)]
+    fn ascent(&self) -> i32 {
+        match self {
+            Self::Mono(chain) => chain[0].0.baseline as i32 + 1,
+            Self::Bdf(font) => font.ascent,
+        }
+    }
+
+    fn glyph(&self, c: char) -> Option<Glyph<'_>> {
+        match self {
+            Self::Mono(chain) => chain
+                .iter()
+                .find(|(_, mapping)| covers(mapping, c))
+                .map(|(font, _)| Glyph::Mono(font)),
+            Self::Bdf(font) => font.glyphs.get(&c).map(Glyph::Bdf),
+        }
+    }
+
+    /// What to draw for a character nothing in the chain covers.
+    fn replacement(&self) -> Glyph<'_> {
+        match self {
+            // Unmapped characters draw as the font's own `?`.
+            Self::Mono(chain) => Glyph::Mono(chain[0].0),
+            Self::Bdf(font) => font
+                .default_char
+                .and_then(|d| font.glyphs.get(&d))
+                .map_or(Glyph::Blank(font.default_advance), Glyph::Bdf),
+        }
+    }
+}
+
+impl Glyph<'_> {
+    #[allow(clippy::cast_possible_wrap)]
+    fn advance(&self) -> i32 {
+        match self {
+            Self::Mono(font) => (font.character_size.width + font.character_spacing) as i32,
+            Self::Bdf(glyph) => glyph.advance,
+            Self::Blank(advance) => *advance,
+        }
+    }
+
+    /// Draw `c` with the pen at `origin`: `origin.y` is the first row
+    /// below the baseline, so undescended glyphs end just above it.
+    /// Returns the advance.
+    #[allow(clippy::cast_possible_wrap)]
+    fn draw<D>(
+        &self,
+        c: char,
+        origin: Point,
+        color: Rgb888,
+        canvas: &mut D,
+    ) -> Result<i32, D::Error>
+    where
+        D: DrawTarget<Color = Rgb888>,
+    {
+        match self {
+            Self::Mono(font) => {
+                let top = origin.y - font.baseline as i32 - 1;
+                let style = MonoTextStyle::new(font, color);
+                let mut buf = [0; 4];
+                Text::with_baseline(
+                    c.encode_utf8(&mut buf),
+                    Point::new(origin.x, top),
+                    style,
+                    Baseline::Top,
+                )
+                .draw(canvas)?;
+            }
+            Self::Bdf(glyph) => glyph.draw(origin, color, canvas)?,
+            Self::Blank(_) => {}
+        }
+        Ok(self.advance())
+    }
+}
+
+/// Whether `mapping` has a real glyph (not the replacement) for `c`.
+fn covers(mapping: &StrGlyphMapping<'_>, c: char) -> bool {
+    mapping.ranges().any(|(_, range)| range.contains(&c))
+}
+
+/// The largest built-in size no taller than `height`, for filling in
+/// characters a BDF font lacks.
+fn mono_for_height(height: i32) -> &'static [MonoFace] {
+    [
+        MONO_10X20, MONO_9X15, MONO_6X13, MONO_6X10, MONO_5X8, MONO_5X7,
+    ]
+    .into_iter()
+    .find(|chain| Face::Mono(chain).line_height() <= height)
+    .unwrap_or(MONO_4X6)
+}
+
+/// Combining marks, joiners and variation selectors. None of our
+/// faces can compose them, so they take no space rather than drawing
+/// a stray replacement glyph after the base character (`é` typed as
+/// `e` + U+0301 renders as `e`; `❤️` as `❤`).
+fn is_zero_width(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036F}'
+            | '\u{200B}'..='\u{200F}'
+            | '\u{2060}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{FEFF}'
+    )
+}
+
+/// The built-in symbol face, parsed once.
+fn symbols() -> Arc<BdfFont> {
+    static SYMBOLS: OnceLock<Arc<BdfFont>> = OnceLock::new();
+    SYMBOLS
+        .get_or_init(|| {
+            Arc::new(
+                BdfFont::parse(include_str!("../fonts/symbols.bdf"))
+                    .expect("built-in symbol font parses"),
+            )
+        })
+        .clone()
+}
+
+/* ─── BDF ────────────────────────────────────────────────────────── */
+
+/// A bitmap font parsed from BDF source.
+#[derive(Debug)]
+pub struct BdfFont {
+    /// Pixels above the baseline.
+    ascent: i32,
+    /// Pixels below the baseline.
+    descent: i32,
+    /// Advance for characters with no glyph and no default glyph.
+    default_advance: i32,
+    default_char: Option<char>,
+    glyphs: HashMap<char, BdfGlyph>,
+}
+
+#[derive(Debug)]
+struct BdfGlyph {
+    advance: i32,
+    width: i32,
+    height: i32,
+    /// Offset of the bitmap's left edge from the pen position.
+    x_offset: i32,
+    /// Offset of the bitmap's bottom edge above the baseline.
+    y_offset: i32,
+    /// Rows of `row_bytes` bytes each, MSB-first.
+    row_bytes: usize,
+    bitmap: Vec<u8>,
+}
+
+/// Why a BDF or PCF file couldn't be parsed.
+#[derive(Debug)]
+pub struct BdfError(String);
+
+impl fmt::Display for BdfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "font: {}", self.0)
+    }
+}
+
+impl std::error::Error for BdfError {}
+
+impl BdfFont {
+    /// Parse BDF 2.x source. Glyph encodings are taken as Unicode
+    /// code points (true for ISO10646-1 fonts, and for ISO8859-1 ones
+    /// in their shared range); glyphs with no encoding are dropped.
+    pub fn parse(source: &str) -> Result<Self, BdfError> {
+        let err = |msg: &str| BdfError(msg.to_owned());
+        let mut lines = source.lines().map(str::trim);
+        let mut bbox: Option<[i32; 4]> = None;
+        let mut ascent = None;
+        let mut descent = None;
+        let mut default_char = None;
+        let mut glyphs = HashMap::new();
+
+        while let Some(line) = lines.next() {
+            let (keyword, rest) = line.split_once(' ').unwrap_or((line, ""));
+            match keyword {
+                "FONTBOUNDINGBOX" => {
+                    bbox = Some(parse_ints(rest).ok_or_else(|| err("bad FONTBOUNDINGBOX"))?)
+                }
+                "FONT_ASCENT" => ascent = rest.trim().parse().ok(),
+                "FONT_DESCENT" => descent = rest.trim().parse().ok(),
+                "DEFAULT_CHAR" => {
+                    default_char = rest.trim().parse::<u32>().ok().and_then(char::from_u32);
+                }
+                "STARTCHAR" => {
+                    if let Some((c, glyph)) = parse_glyph(&mut lines)? {
+                        glyphs.insert(c, glyph);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let [bbox_w, bbox_h, _, bbox_y] = bbox.ok_or_else(|| err("missing FONTBOUNDINGBOX"))?;
+        Ok(Self {
+            ascent: ascent.unwrap_or(bbox_h + bbox_y),
+            descent: descent.unwrap_or(-bbox_y),
+            default_advance: bbox_w,
+            default_char,
+            glyphs,
+        })
+    }
+}
+
+impl BdfGlyph {
+    /// Draw with the pen at `origin`, see [`Glyph::draw`].
+    #[allow(clippy::cast_sign_loss)]
+    fn draw<D>(&self, origin: Point, color: Rgb888, canvas: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Rgb888>,
+    {
+        let left = origin.x + self.x_offset;
+        let top = origin.y - (self.y_offset + self.height);
+        let pixels = (0..self.height)
+            .flat_map(|gy| (0..self.width).map(move |gx| (gx, gy)))
+            .filter(|&(gx, gy)| {
+                let byte = self.bitmap[gy as usize * self.row_bytes + gx as usize / 8];
+                byte & (0x80 >> (gx % 8)) != 0
+            })
+            .map(|(gx, gy)| Pixel(Point::new(left + gx, top + gy), color));
+        canvas.draw_iter(pixels)
+    }
+}
+
+fn parse_ints<const N: usize>(s: &str) -> Option<[i32; N]> {
+    let mut out = [0; N];
+    let mut parts = s.split_whitespace();
+    for slot in &mut out {
+        *slot = parts.next()?.parse().ok()?;
+    }
+    Some(out)
+}
+
+/// Parse one `STARTCHAR` … `ENDCHAR` block (the `STARTCHAR` line
+/// already consumed). `None` for glyphs without a usable encoding.
+fn parse_glyph<'a>(
+    lines: &mut impl Iterator<Item = &'a str>,
+) -> Result<Option<(char, BdfGlyph)>, BdfError> {
+    let err = |msg: &str| BdfError(msg.to_owned());
+    let mut encoding = None;
+    let mut advance = None;
+    let mut bbx: Option<[i32; 4]> = None;
+
+    for line in lines.by_ref() {
+        let (keyword, rest) = line.split_once(' ').unwrap_or((line, ""));
+        match keyword {
+            "ENCODING" => {
+                encoding = rest
+                    .split_whitespace()
+                    .next()
+                    .and_then(|e| e.parse::<u32>().ok())
+                    .and_then(char::from_u32);
+            }
+            "DWIDTH" => advance = parse_ints::<2>(rest).map(|[x, _]| x),
+            "BBX" => bbx = Some(parse_ints(rest).ok_or_else(|| err("bad BBX"))?),
+            "BITMAP" => break,
+            "ENDCHAR" => return Ok(None),
+            _ => {}
+        }
+    }
+
+    let [width, height, x_offset, y_offset] = bbx.ok_or_else(|| err("glyph without BBX"))?;
+    let (w, h) = (
+        usize::try_from(width).map_err(|_| err("negative BBX width"))?,
+        usize::try_from(height).map_err(|_| err("negative BBX height"))?,
+    );
+    let row_bytes = w.div_ceil(8);
+    let mut bitmap = Vec::with_capacity(row_bytes * h);
+    for line in lines.by_ref() {
+        if line == "ENDCHAR" {
+            break;
+        }
+        // Rows may be padded past the glyph width; keep what we need.
+        let row = (0..row_bytes).map(|i| {
+            line.get(i * 2..i * 2 + 2)
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                .unwrap_or(0)
+        });
+        bitmap.extend(row);
+    }
+    bitmap.resize(row_bytes * h, 0);
+
+    Ok(encoding.map(|c| {
+        (
+            c,
+            BdfGlyph {
+                advance: advance.unwrap_or(width),
+                width,
+                height,
+                x_offset,
+                y_offset,
+                row_bytes,
+                bitmap,
+            },
+        )
+    }))
+}
+
+/* ─── PCF ────────────────────────────────────────────────────────── */
+
+// Table types in a PCF table of contents.
+const PCF_ACCELERATORS: u32 = 1 << 1;
+const PCF_METRICS: u32 = 1 << 2;
+const PCF_BITMAPS: u32 = 1 << 3;
+const PCF_BDF_ENCODINGS: u32 = 1 << 5;
+const PCF_BDF_ACCELERATORS: u32 = 1 << 8;
+
+/// Table format for metrics stored as biased bytes.
+const PCF_COMPRESSED_METRICS: u32 = 0x100;
+
+impl BdfFont {
+    /// Parse a PCF font, the compiled form X11 ships BDF in. Must be
+    /// uncompressed — gunzip a `.pcf.gz` first. Encodings are taken as
+    /// Unicode, as in [`BdfFont::parse`].
+    pub fn parse_pcf(data: &[u8]) -> Result<Self, BdfError> {
+        let err = |msg: &str| BdfError(msg.to_owned());
+        if data.get(..4) != Some(b"\x01fcp") {
+            return Err(err("bad PCF header"));
+        }
+        let mut toc = PcfReader::new(data, 4);
+        let mut tables = HashMap::new();
+        for _ in 0..toc.u32()? {
+            let [kind, _format, _size, offset] = [toc.u32()?, toc.u32()?, toc.u32()?, toc.u32()?];
+            tables.insert(kind, offset as usize);
+        }
+        // Each table leads with its format, always little-endian; the
+        // rest of it is in the byte order the format names.
+        let table = |kind| {
+            let offset = *tables.get(&kind).ok_or_else(|| err("missing PCF table"))?;
+            let mut reader = PcfReader::new(data, offset);
+            let format = reader.u32()?;
+            reader.msb = format & (1 << 2) != 0;
+            Ok::<_, BdfError>((reader, format))
+        };
+
+        let (mut r, format) = table(PCF_METRICS)?;
+        let metrics = if format & 0xffff_ff00 == PCF_COMPRESSED_METRICS {
+            let count = r.u16()?;
+            (0..count)
+                .map(|_| {
+                    let mut field = || Ok::<_, BdfError>(i32::from(r.u8()?) - 0x80);
+                    Ok([field()?, field()?, field()?, field()?, field()?])
+                })
+                .collect::<Result<Vec<_>, BdfError>>()?
+        } else {
+            let count = r.u32()?;
+            (0..count)
+                .map(|_| {
+                    let mut field = || Ok::<_, BdfError>(i32::from(r.i16()?));
+                    let metrics = [field()?, field()?, field()?, field()?, field()?];
+                    field()?; // attributes
+                    Ok(metrics)
+                })
+                .collect::<Result<Vec<_>, BdfError>>()?
+        };
+
+        let (mut r, format) = table(PCF_BITMAPS)?;
+        if r.u32()? as usize != metrics.len() {
+            return Err(err("bitmap and metrics counts differ"));
+        }
+        let offsets = (0..metrics.len())
+            .map(|_| r.u32().map(|o| o as usize))
+            .collect::<Result<Vec<_>, _>>()?;
+        let sizes = [r.u32()?, r.u32()?, r.u32()?, r.u32()?];
+        let mut bits = r.bytes(sizes[format as usize & 3] as usize)?.to_vec();
+        // Normalize to MSB-first bits, bytes in reading order.
+        let msb_byte = format & (1 << 2) != 0;
+        let msb_bit = format & (1 << 3) != 0;
+        let scan_unit = 1 << ((format >> 4) & 3);
+        if msb_byte != msb_bit && scan_unit > 1 {
+            bits.chunks_exact_mut(scan_unit).for_each(<[u8]>::reverse);
+        }
+        if !msb_bit {
+            bits.iter_mut().for_each(|b| *b = b.reverse_bits());
+        }
+        let pad = 1usize << (format & 3);
+
+        let mut glyphs = HashMap::new();
+        let (mut r, _) = table(PCF_BDF_ENCODINGS)?;
+        let [min_byte2, max_byte2, min_byte1, max_byte1, default] =
+            [r.u16()?, r.u16()?, r.u16()?, r.u16()?, r.u16()?];
+        for byte1 in min_byte1..=max_byte1 {
+            for byte2 in min_byte2..=max_byte2 {
+                let index = usize::from(r.u16()?);
+                let code = u32::from(byte1) << 8 | u32::from(byte2);
+                let (Some(c), Some(&[left, right, advance, ascent, descent])) =
+                    (char::from_u32(code), metrics.get(index))
+                else {
+                    continue; // 0xFFFF: no glyph
+                };
+                let (width, height) = (right - left, ascent + descent);
+                let (w, h) = (
+                    usize::try_from(width).map_err(|_| err("negative glyph width"))?,
+                    usize::try_from(height).map_err(|_| err("negative glyph height"))?,
+                );
+                let row_bytes = w.div_ceil(8 * pad) * pad;
+                let bitmap = bits
+                    .get(offsets[index]..offsets[index] + row_bytes * h)
+                    .ok_or_else(|| err("glyph past the end of its bitmap"))?
+                    .to_vec();
+                let glyph = BdfGlyph {
+                    advance,
+                    width,
+                    height,
+                    x_offset: left,
+                    y_offset: -descent,
+                    row_bytes,
+                    bitmap,
+                };
+                glyphs.insert(c, glyph);
+            }
+        }
+
+        let default_advance = metrics.iter().map(|m| m[2]).max().unwrap_or(0);
+        let (ascent, descent) =
+            match table(PCF_BDF_ACCELERATORS).or_else(|_| table(PCF_ACCELERATORS)) {
+                Ok((mut r, _)) => {
+                    r.bytes(8)?; // flags
+                    (r.i32()?, r.i32()?)
+                }
+                Err(_) => (
+                    metrics.iter().map(|m| m[3]).max().unwrap_or(0),
+                    metrics.iter().map(|m| m[4]).max().unwrap_or(0),
+                ),
+            };
+        Ok(Self {
+            ascent,
+            descent,
+            default_advance,
+            default_char: char::from_u32(u32::from(default)),
+            glyphs,
+        })
+    }
+}
+
+/// Cursor over a PCF table's fields.
+struct PcfReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    /// Whether multi-byte fields are big-endian.
+    msb: bool,
+}
+
+impl<'a> PcfReader<'a> {
+    fn new(data: &'a [u8], pos: usize) -> Self {
+        Self {
+            data,
+            pos,
+            msb: false,
+        }
+    }
+
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], BdfError> {
+        let bytes = self
+            .pos
+            .checked_add(len)
+            .and_then(|end| self.data.get(self.pos..end))
+            .ok_or_else(|| BdfError("truncated PCF table".to_owned()))?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], BdfError> {
+        let mut out: [u8; N] = self.bytes(N)?.try_into().expect("N bytes");
+        if !self.msb {
+            out.reverse();
+        }
+        Ok(out)
+    }
+
+    fn u8(&mut self) -> Result<u8, BdfError> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, BdfError> {
+        self.array().map(u16::from_be_bytes)
+    }
+
+    fn i16(&mut self) -> Result<i16, BdfError> {
+        self.array().map(i16::from_be_bytes)
+    }
+
+    fn u32(&mut self) -> Result<u32, BdfError> {
+        self.array().map(u32::from_be_bytes)
+    }
+
+    fn i32(&mut self) -> Result<i32, BdfError> {
+        self.array().map(i32::from_be_bytes)
+    }
+}
+
+/* ─── registry ───────────────────────────────────────────────────── */
+
+fn registry() -> &'static RwLock<HashMap<String, Arc<BdfFont>>> {
+    static REGISTRY: OnceLock<RwLock<HashMap<String, Arc<BdfFont>>>> = OnceLock::new();
+    REGISTRY.get_or_init(RwLock::default)
+}
+
+/// Make `font` selectable as `FontId::Bdf(name)`. Replaces any font
+/// already registered under that name.
+pub fn register_bdf(name: &str, font: BdfFont) {
+    registry()
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(name.to_owned(), Arc::new(font));
+}
+
+fn fallback_names() -> &'static RwLock<Vec<String>> {
+    static FALLBACKS: OnceLock<RwLock<Vec<String>>> = OnceLock::new();
+    FALLBACKS.get_or_init(RwLock::default)
+}
+
+/// Use the registered BDF fonts `names`, in order, for characters no
+/// built-in face covers. Names not (yet) registered are skipped when
+/// text is drawn.
+pub fn set_fallbacks(names: Vec<String>) {
+    *fallback_names()
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = names;
+}
+
+fn lookup_bdf(name: &str) -> Option<Arc<BdfFont>> {
+    registry()
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(name)
+        .cloned()
+}
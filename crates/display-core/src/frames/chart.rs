@@ -0,0 +1,238 @@
+//! Chart mode. Plots a series of numeric samples — request rates,
+//! temperatures — as a line, filled area or bars, newest at the right
+//! edge. The vertical axis fits the visible samples unless `min` /
+//! `max` pin it; threshold bands recolor everything drawn above each
+//! threshold. Samples come with the scene; renderer is pure.
+
+use embedded_graphics::{
+    pixelcolor::Rgb888,
+    prelude::*,
+    primitives::{PrimitiveStyle, Rectangle},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::font::{Font, FontId};
+use crate::text::Rgb;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum ChartStyle {
+    /// One pixel per sample, joined column to column.
+    #[default]
+    Sparkline,
+    /// Sparkline filled down to the bottom of the plot.
+    Area,
+    /// `bar_width`-wide bars with a one-pixel gap.
+    Bar,
+}
+
+/// Everything drawn at or above `value` takes `color`. With several
+/// thresholds the highest one reached wins.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ChartThreshold {
+    pub value: f32,
+    pub color: Rgb,
+}
+
+/// Persisted shape — what the dash writes into `panels.mode_config`
+/// for chart-mode panels. The driver may also read a `source` key
+/// from the same config and fill `samples` from that data source's
+/// history each frame.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ChartScene {
+    /// Oldest first. Only the newest ones that fit are drawn.
+    #[serde(default)]
+    pub samples: Vec<f32>,
+    #[serde(default)]
+    pub style: ChartStyle,
+    /// Pin the bottom of the axis. Absent = lowest visible sample.
+    #[serde(default)]
+    pub min: Option<f32>,
+    /// Pin the top of the axis. Absent = highest visible sample.
+    #[serde(default)]
+    pub max: Option<f32>,
+    /// Draw the axis max and min in a left gutter.
+    #[serde(default)]
+    pub labels: bool,
+    #[serde(default = "default_chart_color")]
+    pub color: Rgb,
+    #[serde(default = "default_label_color")]
+    pub label_color: Rgb,
+    #[serde(default)]
+    pub font: FontId,
+    #[serde(default)]
+    pub thresholds: Vec<ChartThreshold>,
+    #[serde(default = "default_bar_width")]
+    pub bar_width: u32,
+}
+
+impl Default for ChartScene {
+    fn default() -> Self {
+        Self {
+            samples: Vec::new(),
+            style: ChartStyle::default(),
+            min: None,
+            max: None,
+            labels: false,
+            color: default_chart_color(),
+            label_color: default_label_color(),
+            font: FontId::default(),
+            thresholds: Vec::new(),
+            bar_width: default_bar_width(),
+        }
+    }
+}
+
+fn default_chart_color() -> Rgb {
+    Rgb {
+        r: 0x4d,
+        g: 0xff,
+        b: 0x88,
+    }
+}
+
+fn default_label_color() -> Rgb {
+    Rgb {
+        r: 0x80,
+        g: 0x80,
+        b: 0x80,
+    }
+}
+
+fn default_bar_width() -> u32 {
+    3
+}
+
+/// Pixels between the label gutter and the plot.
+const GUTTER_GAP: i32 = 1;
+
+#[allow(clippy::cast_possible_wrap)]
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_precision_loss)]
+pub fn render<D>(frame: &ChartScene, _step: usize, canvas: &mut D) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = Rgb888> + OriginDimensions,
+{
+    let size = canvas.size();
+    let (canvas_w, canvas_h) = (size.width as i32, size.height as i32);
+    let samples: Vec<f32> = frame
+        .samples
+        .iter()
+        .copied()
+        .filter(|v| v.is_finite())
+        .collect();
+
+    // The labels depend on the scale and the scale on how many samples
+    // fit beside them, so size the gutter from the full series first.
+    let font = Font::resolve(&frame.font);
+    let gutter = if frame.labels {
+        let (lo, hi) = axis(frame, &samples);
+        let label_w = font
+            .text_width(&format_value(hi))
+            .max(font.text_width(&format_value(lo)));
+        label_w + GUTTER_GAP
+    } else {
+        0
+    };
+    let plot_w = (canvas_w - gutter).max(0);
+    let slot = match frame.style {
+        ChartStyle::Bar => frame.bar_width.max(1) as i32 + 1,
+        ChartStyle::Sparkline | ChartStyle::Area => 1,
+    };
+    let visible = &samples[samples.len().saturating_sub((plot_w / slot) as usize)..];
+    let (lo, hi) = axis(frame, visible);
+
+    if frame.labels {
+        let color = frame.label_color.into();
+        font.draw(&format_value(hi), Point::new(0, 0), color, canvas)?;
+        let bottom = Point::new(0, canvas_h - font.line_height());
+        font.draw(&format_value(lo), bottom, color, canvas)?;
+    }
+
+    // Row for a value: `hi` on row 0, `lo` on the bottom row.
+    let span = hi - lo;
+    let row = |v: f32| {
+        let t = ((v - lo) / span).clamp(0.0, 1.0);
+        (canvas_h - 1) - (t * (canvas_h - 1) as f32).round() as i32
+    };
+    // Color for a row: the highest threshold whose row it reaches.
+    let color_at = |y: i32| -> Rgb888 {
+        frame
+            .thresholds
+            .iter()
+            .filter(|t| y <= row(t.value))
+            .max_by(|a, b| a.value.total_cmp(&b.value))
+            .map_or(frame.color, |t| t.color)
+            .into()
+    };
+    let column = |x: i32, top: i32, bottom: i32, canvas: &mut D| {
+        canvas.draw_iter((top..=bottom).map(|y| Pixel(Point::new(x, y), color_at(y))))
+    };
+
+    // Right-align so the newest sample sits against the right edge.
+    let left = canvas_w - visible.len() as i32 * slot;
+    let mut previous = None;
+    for (i, &value) in visible.iter().enumerate() {
+        let x = left + i as i32 * slot;
+        let y = row(value);
+        match frame.style {
+            ChartStyle::Sparkline => {
+                // Span to the previous sample's row so steep changes
+                // stay a connected line.
+                let (top, bottom) = match previous {
+                    Some(prev) if prev < y => (prev + 1, y),
+                    Some(prev) if prev > y => (y, prev - 1),
+                    _ => (y, y),
+                };
+                column(x, top, bottom, canvas)?;
+            }
+            ChartStyle::Area => column(x, y, canvas_h - 1, canvas)?,
+            ChartStyle::Bar => {
+                for dx in 0..slot - 1 {
+                    column(x + dx, y, canvas_h - 1, canvas)?;
+                }
+            }
+        }
+        previous = Some(y);
+    }
+
+    if visible.is_empty() {
+        // Nothing to plot yet: a dim baseline says "chart, no data".
+        let baseline = Rectangle::new(
+            Point::new(gutter, canvas_h - 1),
+            Size::new(plot_w.unsigned_abs(), 1),
+        );
+        baseline
+            .into_styled(PrimitiveStyle::with_fill(frame.label_color.into()))
+            .draw(canvas)?;
+    }
+    Ok(())
+}
+
+/// Bottom and top of the vertical axis: the pinned `min` / `max`, else
+/// the extremes of `samples`. Never zero-height, so a flat series
+/// draws mid-panel instead of dividing by zero.
+fn axis(frame: &ChartScene, samples: &[f32]) -> (f32, f32) {
+    let lowest = samples.iter().copied().reduce(f32::min).unwrap_or(0.0);
+    let highest = samples.iter().copied().reduce(f32::max).unwrap_or(0.0);
+    let lo = frame.min.unwrap_or(lowest);
+    let hi = frame.max.unwrap_or(highest);
+    if hi > lo {
+        (lo, hi)
+    } else {
+        (lo - 1.0, lo + 1.0)
+    }
+}
+
+/// Short axis label: `12`, `3.5`, `12k`, `1.2M`.
+pub(crate) fn format_value(v: f32) -> String {
+    let magnitude = v.abs();
+    if magnitude >= 1_000_000.0 {
+        format!("{:.1}M", v / 1_000_000.0)
+    } else if magnitude >= 10_000.0 {
+        format!("{:.0}k", v / 1_000.0)
+    } else if magnitude >= 100.0 || v.fract() == 0.0 {
+        format!("{v:.0}")
+    } else {
+        format!("{v:.1}")
+    }
+}
@@ -1,15 +1,20 @@
-//! Clock mode. Renders the current time, centered on the panel.
+//! Clock mode. Renders the current time, centered on the panel, in
+//! one of several [`ClockStyle`]s: font digits, large seven-segment
+//! digits, an analog face, a binary clock or a word clock. Optional
+//! weekday and date lines sit along the bottom, and a list of
+//! [`ClockZone`]s turns it into a world clock instead.
 //! Caller supplies `now` per frame; renderer is pure.
 
 use embedded_graphics::{
-    mono_font::{ascii::FONT_5X8, MonoTextStyleBuilder},
     pixelcolor::Rgb888,
     prelude::*,
-    text::Text,
+    primitives::{Circle, Line, PrimitiveStyle, Rectangle},
 };
 use serde::{Deserialize, Serialize};
 
-use crate::text::Rgb;
+use crate::color::Recolor;
+use crate::font::{Font, FontId};
+use crate::text::{Rgb, TextEntryColor};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Deserialize, Serialize)]
 pub enum ClockFormat {
@@ -18,11 +23,73 @@ pub enum ClockFormat {
     H12,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum ClockStyle {
+    /// The time as text in the configured `font`.
+    #[default]
+    Digital,
+    /// Blocky seven-segment digits sized to fill the panel width.
+    Segment,
+    /// Round face with tick marks and hour/minute (and, with
+    /// `show_seconds`, second) hands.
+    Analog,
+    /// One column per BCD digit, bits lit bottom-up.
+    Binary,
+    /// "IT IS QUARTER PAST TEN", to the nearest five minutes.
+    Words,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Deserialize, Serialize)]
 pub struct ClockTime {
     pub hour: u8,   // 0..23
     pub minute: u8, // 0..59
     pub second: u8, // 0..59
+    #[serde(default)]
+    pub year: i32,
+    #[serde(default)]
+    pub month: u8, // 1..12
+    #[serde(default)]
+    pub day: u8, // 1..31
+    #[serde(default)]
+    pub weekday: u8, // 0..6, Monday first
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum DateFormat {
+    /// `2026-10-18`
+    Iso,
+    /// `18/10/2026`
+    DayMonthYear,
+    /// `10/18/2026`
+    MonthDayYear,
+    /// `18 OCT`
+    DayMonth,
+    /// `OCT 18`
+    MonthDay,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum WeekdayFormat {
+    /// `SUN`
+    Short,
+    /// `SUNDAY`
+    Long,
+}
+
+/// One row of a world clock, as persisted.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ClockZone {
+    /// Short name drawn before the time, e.g. `"NYC"`.
+    pub label: String,
+    /// IANA timezone, as [`ClockSceneConfig::timezone`].
+    pub timezone: String,
+}
+
+/// One row of a world clock, ready to draw.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ZoneTime {
+    pub label: String,
+    pub now: ClockTime,
 }
 
 /// Persisted shape — what the dash writes into `panels.mode_config`
@@ -36,12 +103,29 @@ pub struct ClockSceneConfig {
     pub show_seconds: bool,
     #[serde(default)]
     pub show_meridiem: bool,
+    #[serde(default)]
+    pub style: ClockStyle,
     /// IANA timezone (e.g. "America/Los_Angeles"); `None` falls back
     /// to the Pi's system local time.
     #[serde(default)]
     pub timezone: Option<String>,
     #[serde(default = "default_clock_color")]
     pub color: Rgb,
+    #[serde(default)]
+    pub font: FontId,
+    /// Overrides `color` with a gradient, palette, pulse or rainbow.
+    #[serde(default)]
+    pub fill: Option<TextEntryColor>,
+    /// Weekday line under the time.
+    #[serde(default)]
+    pub weekday: Option<WeekdayFormat>,
+    /// Date line under the time (and weekday).
+    #[serde(default)]
+    pub date: Option<DateFormat>,
+    /// When non-empty, one labeled row per zone replaces the single
+    /// readout; `timezone`, `style`, `weekday` and `date` are unused.
+    #[serde(default)]
+    pub zones: Vec<ClockZone>,
 }
 
 impl Default for ClockSceneConfig {
@@ -50,23 +134,45 @@ impl Default for ClockSceneConfig {
             format: ClockFormat::H24,
             show_seconds: false,
             show_meridiem: false,
+            style: ClockStyle::Digital,
             timezone: None,
             color: default_clock_color(),
+            font: FontId::default(),
+            fill: None,
+            weekday: None,
+            date: None,
+            zones: Vec::new(),
         }
     }
 }
 
 impl ClockSceneConfig {
     /// Combine the persisted config with a freshly-sampled time into
-    /// a render-ready `ClockScene`.
+    /// a render-ready `ClockScene`. `zone_times` holds the time in
+    /// each of `zones`, in order.
     #[must_use]
-    pub fn into_frame(self, now: ClockTime) -> ClockScene {
+    pub fn into_frame(self, now: ClockTime, zone_times: &[ClockTime]) -> ClockScene {
+        let zones = self
+            .zones
+            .into_iter()
+            .zip(zone_times)
+            .map(|(zone, &now)| ZoneTime {
+                label: zone.label,
+                now,
+            })
+            .collect();
         ClockScene {
             format: self.format,
             show_seconds: self.show_seconds,
             show_meridiem: self.show_meridiem,
+            style: self.style,
             color: self.color,
             now,
+            font: self.font,
+            fill: self.fill,
+            weekday: self.weekday,
+            date: self.date,
+            zones,
         }
     }
 }
@@ -100,6 +206,23 @@ pub struct ClockScene {
     /// `format` is H24.
     #[serde(default)]
     pub show_meridiem: bool,
+    /// How the time is drawn. `font` only applies to `Digital`,
+    /// `Words` and a `Segment` clock's meridiem marker.
+    #[serde(default)]
+    pub style: ClockStyle,
+    /// Glyph set; `10x20` makes a wall-clock-sized readout.
+    #[serde(default)]
+    pub font: FontId,
+    /// Overrides `color`, spread across the readout.
+    #[serde(default)]
+    pub fill: Option<TextEntryColor>,
+    #[serde(default)]
+    pub weekday: Option<WeekdayFormat>,
+    #[serde(default)]
+    pub date: Option<DateFormat>,
+    /// World-clock rows; see [`ClockSceneConfig::zones`].
+    #[serde(default)]
+    pub zones: Vec<ZoneTime>,
 }
 
 impl Default for ClockScene {
@@ -110,42 +233,449 @@ impl Default for ClockScene {
             color: default_clock_color(),
             now: ClockTime::default(),
             show_meridiem: false,
+            style: ClockStyle::Digital,
+            font: FontId::default(),
+            fill: None,
+            weekday: None,
+            date: None,
+            zones: Vec::new(),
         }
     }
 }
 
-#[allow(clippy::cast_possible_truncation)]
 #[allow(clippy::cast_possible_wrap)]
-pub fn render<D>(frame: &ClockScene, canvas: &mut D) -> Result<(), D::Error>
+pub fn render<D>(frame: &ClockScene, step: usize, canvas: &mut D) -> Result<(), D::Error>
 where
     D: DrawTarget<Color = Rgb888> + OriginDimensions,
 {
-    let font = FONT_5X8;
-    let canvas_size = canvas.size();
-    let canvas_w = canvas_size.width as i32;
-    let canvas_h = canvas_size.height as i32;
-
-    let text = format_time(frame);
-    let chars = text.chars().count() as i32;
-    let glyph_w = (font.character_size.width + font.character_spacing) as i32;
-    let glyph_h = font.character_size.height as i32;
-    let text_w = chars * glyph_w;
-
-    let x = (canvas_w - text_w) / 2;
-    // embedded_graphics Text positions at the baseline (Alphabetic by
-    // default), which sits at glyph_h - 1. Center vertically with that.
-    let y = (canvas_h + glyph_h) / 2 - 1;
-
-    let style = MonoTextStyleBuilder::new()
-        .font(&font)
-        .text_color(frame.color.into())
-        .build();
-    Text::new(&text, Point::new(x, y), style).draw(canvas)?;
+    let size = canvas.size();
+    match &frame.fill {
+        None => draw_clock(frame, size, frame.color.into(), canvas),
+        Some(fill) => {
+            // A lone digital readout spreads the fill across its text;
+            // everything else across the panel.
+            let bounds = match frame.style {
+                ClockStyle::Digital if frame.zones.is_empty() => {
+                    let font = Font::resolve(&frame.font);
+                    let text_w = font.text_width(&format_time(frame, frame.now));
+                    Rectangle::new(
+                        centered(face_size(frame, size), text_w, font.line_height()),
+                        Size::new(text_w.unsigned_abs(), font.line_height().unsigned_abs()),
+                    )
+                }
+                _ => Rectangle::new(Point::zero(), size),
+            };
+            let mut painted = Recolor {
+                inner: canvas,
+                paint: |p| fill.at(p, bounds, step),
+            };
+            draw_clock(frame, size, Rgb888::WHITE, &mut painted)
+        }
+    }
+}
+
+/// The face centered above any weekday/date lines, which are drawn in
+/// the default font along the bottom; or the world-clock rows.
+#[allow(clippy::cast_possible_wrap)]
+fn draw_clock<D>(
+    frame: &ClockScene,
+    size: Size,
+    color: Rgb888,
+    canvas: &mut D,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = Rgb888>,
+{
+    if !frame.zones.is_empty() {
+        return draw_zones(frame, size, color, canvas);
+    }
+    let face = face_size(frame, size);
+    draw_face(frame, face, color, canvas)?;
+    let font = Font::resolve(&FontId::default());
+    let mut y = face.height as i32;
+    for line in date_lines(frame) {
+        let x = (size.width as i32 - font.text_width(&line)) / 2;
+        font.draw(&line, Point::new(x, y), color, canvas)?;
+        y += font.line_height() + 1;
+    }
     Ok(())
 }
 
-fn format_time(frame: &ClockScene) -> String {
-    let (h, m, s) = (frame.now.hour, frame.now.minute, frame.now.second);
+/// The part of a `size` canvas left for the face above the date lines.
+fn face_size(frame: &ClockScene, size: Size) -> Size {
+    let lines = u32::try_from(date_lines(frame).len()).unwrap_or(0);
+    let line_h = Font::resolve(&FontId::default())
+        .line_height()
+        .unsigned_abs()
+        + 1;
+    Size::new(size.width, size.height.saturating_sub(lines * line_h))
+}
+
+const WEEKDAYS: [&str; 7] = [
+    "MONDAY",
+    "TUESDAY",
+    "WEDNESDAY",
+    "THURSDAY",
+    "FRIDAY",
+    "SATURDAY",
+    "SUNDAY",
+];
+
+const MONTHS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+/// Weekday then date, whichever are configured.
+fn date_lines(frame: &ClockScene) -> Vec<String> {
+    let now = frame.now;
+    let mut lines = Vec::new();
+    if let Some(format) = frame.weekday {
+        let name = WEEKDAYS[usize::from(now.weekday % 7)];
+        lines.push(match format {
+            WeekdayFormat::Short => name[..3].to_owned(),
+            WeekdayFormat::Long => name.to_owned(),
+        });
+    }
+    if let Some(format) = frame.date {
+        let (y, m, d) = (now.year, now.month, now.day);
+        let month = MONTHS[usize::from(m.clamp(1, 12) - 1)];
+        lines.push(match format {
+            DateFormat::Iso => format!("{y:04}-{m:02}-{d:02}"),
+            DateFormat::DayMonthYear => format!("{d:02}/{m:02}/{y:04}"),
+            DateFormat::MonthDayYear => format!("{m:02}/{d:02}/{y:04}"),
+            DateFormat::DayMonth => format!("{d} {month}"),
+            DateFormat::MonthDay => format!("{month} {d}"),
+        });
+    }
+    lines
+}
+
+/// World clock: label left, time right, one row per zone, the block
+/// centered vertically.
+#[allow(clippy::cast_possible_wrap)]
+fn draw_zones<D>(
+    frame: &ClockScene,
+    size: Size,
+    color: Rgb888,
+    canvas: &mut D,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = Rgb888>,
+{
+    let font = Font::resolve(&frame.font);
+    let line_h = font.line_height() + 1;
+    let rows = frame.zones.len() as i32;
+    let mut y = centered(size, 0, rows * line_h - 1).y;
+    for zone in &frame.zones {
+        let time = format_time(frame, zone.now);
+        font.draw(&zone.label, Point::new(0, y), color, canvas)?;
+        let x = size.width as i32 - font.text_width(&time);
+        font.draw(&time, Point::new(x, y), color, canvas)?;
+        y += line_h;
+    }
+    Ok(())
+}
+
+fn draw_face<D>(
+    frame: &ClockScene,
+    size: Size,
+    color: Rgb888,
+    canvas: &mut D,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = Rgb888>,
+{
+    match frame.style {
+        ClockStyle::Digital => {
+            let font = Font::resolve(&frame.font);
+            let text = format_time(frame, frame.now);
+            let top_left = centered(size, font.text_width(&text), font.line_height());
+            font.draw(&text, top_left, color, canvas)?;
+            Ok(())
+        }
+        ClockStyle::Segment => draw_segments(frame, size, color, canvas),
+        ClockStyle::Analog => draw_analog(frame, size, color, canvas),
+        ClockStyle::Binary => draw_binary(frame, size, color, canvas),
+        ClockStyle::Words => draw_words(frame, size, color, canvas),
+    }
+}
+
+/// Top-left of a `w` x `h` box centered on a canvas of `size`.
+#[allow(clippy::cast_possible_wrap)]
+fn centered(size: Size, w: i32, h: i32) -> Point {
+    Point::new((size.width as i32 - w) / 2, (size.height as i32 - h) / 2)
+}
+
+/// Segments lit for each digit, bit 0 = top (a) round to bit 6 =
+/// middle (g).
+const SEGMENTS: [u8; 10] = [0x3f, 0x06, 0x5b, 0x4f, 0x66, 0x6d, 0x7d, 0x07, 0x7f, 0x6f];
+
+/// Gap between seven-segment digits and colons.
+const SEGMENT_GAP: i32 = 2;
+
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_possible_wrap)]
+fn draw_segments<D>(
+    frame: &ClockScene,
+    size: Size,
+    color: Rgb888,
+    canvas: &mut D,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = Rgb888>,
+{
+    let text = format_time(frame, frame.now);
+    // Digits and colons go large; the meridiem letter stays in `font`.
+    let split = text
+        .find(|c: char| !c.is_ascii_digit() && c != ':')
+        .unwrap_or(text.len());
+    let (time, marker) = text.split_at(split);
+    let font = Font::resolve(&frame.font);
+    let marker_w = font.text_width(marker);
+    let digits = time.chars().filter(char::is_ascii_digit).count() as i32;
+    let colons = time.len() as i32 - digits;
+    let items = time.len() as i32 + i32::from(!marker.is_empty());
+
+    let canvas_w = size.width as i32;
+    let thickness = (canvas_w / digits.max(1) / 5).max(1);
+    let spare = canvas_w - colons * thickness - marker_w - (items - 1) * SEGMENT_GAP;
+    let digit_w = (spare / digits.max(1)).max(3);
+    let digit_h = (2 * digit_w).min(size.height as i32);
+    let total_w = digits * digit_w + colons * thickness + marker_w + (items - 1) * SEGMENT_GAP;
+    let origin = centered(size, total_w, digit_h);
+
+    let style = PrimitiveStyle::with_fill(color);
+    let fill = |x: i32, y: i32, w: i32, h: i32| {
+        Rectangle::new(
+            Point::new(x, y),
+            Size::new(w.unsigned_abs(), h.unsigned_abs()),
+        )
+        .into_styled(style)
+    };
+    let (t, h) = (thickness, digit_h);
+    let mid = (h - t) / 2;
+    let mut x = origin.x;
+    let y = origin.y;
+    for c in time.chars() {
+        if let Some(digit) = c.to_digit(10) {
+            let w = digit_w;
+            let segments = [
+                fill(x, y, w, t),
+                fill(x + w - t, y, t, mid + t),
+                fill(x + w - t, y + mid, t, h - mid),
+                fill(x, y + h - t, w, t),
+                fill(x, y + mid, t, h - mid),
+                fill(x, y, t, mid + t),
+                fill(x, y + mid, w, t),
+            ];
+            for (bit, segment) in segments.iter().enumerate() {
+                if SEGMENTS[digit as usize] & (1 << bit) != 0 {
+                    segment.draw(canvas)?;
+                }
+            }
+            x += w;
+        } else {
+            fill(x, y + h / 3 - t / 2, t, t).draw(canvas)?;
+            fill(x, y + 2 * h / 3 - t / 2, t, t).draw(canvas)?;
+            x += t;
+        }
+        x += SEGMENT_GAP;
+    }
+    if !marker.is_empty() {
+        font.draw(
+            marker,
+            Point::new(x, y + h - font.line_height()),
+            color,
+            canvas,
+        )?;
+    }
+    Ok(())
+}
+
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_possible_wrap)]
+fn draw_analog<D>(
+    frame: &ClockScene,
+    size: Size,
+    color: Rgb888,
+    canvas: &mut D,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = Rgb888>,
+{
+    let diameter = size.width.min(size.height);
+    let radius = (diameter / 2) as f32 - 1.0;
+    let center = Point::new(size.width as i32 / 2, size.height as i32 / 2);
+    // Where a hand `length` long at `turns` of a full revolution ends.
+    let tip = |turns: f32, length: f32| {
+        let angle = turns * std::f32::consts::TAU;
+        center
+            + Point::new(
+                (angle.sin() * length).round() as i32,
+                (-angle.cos() * length).round() as i32,
+            )
+    };
+
+    for hour in 0..12_u8 {
+        let turns = f32::from(hour) / 12.0;
+        // Quarter hours get a longer tick.
+        let inner = if hour % 3 == 0 {
+            radius * 0.8
+        } else {
+            radius * 0.9
+        };
+        Line::new(tip(turns, inner), tip(turns, radius))
+            .into_styled(PrimitiveStyle::with_stroke(color, 1))
+            .draw(canvas)?;
+    }
+
+    let now = frame.now;
+    let (h, m, s) = (
+        f32::from(now.hour % 12),
+        f32::from(now.minute),
+        f32::from(now.second),
+    );
+    let width = (diameter / 32).max(1);
+    let hands = [
+        ((h + m / 60.0) / 12.0, 0.5, width + 1),
+        ((m + s / 60.0) / 60.0, 0.75, width),
+    ];
+    for (turns, length, stroke) in hands {
+        Line::new(center, tip(turns, radius * length))
+            .into_styled(PrimitiveStyle::with_stroke(color, stroke))
+            .draw(canvas)?;
+    }
+    if frame.show_seconds {
+        Line::new(center, tip(s / 60.0, radius * 0.85))
+            .into_styled(PrimitiveStyle::with_stroke(color, 1))
+            .draw(canvas)?;
+    }
+    Circle::with_center(center, 3)
+        .into_styled(PrimitiveStyle::with_fill(color))
+        .draw(canvas)?;
+    Ok(())
+}
+
+#[allow(clippy::cast_possible_wrap)]
+fn draw_binary<D>(
+    frame: &ClockScene,
+    size: Size,
+    color: Rgb888,
+    canvas: &mut D,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = Rgb888>,
+{
+    let (hour, _) = display_hour(frame.format, frame.now.hour);
+    let mut values = vec![
+        hour / 10,
+        hour % 10,
+        frame.now.minute / 10,
+        frame.now.minute % 10,
+    ];
+    if frame.show_seconds {
+        values.extend([frame.now.second / 10, frame.now.second % 10]);
+    }
+    let columns = values.len() as i32;
+    let cell = (size.width as i32 / columns).min(size.height as i32 / 4);
+    let dot = (cell - 2).max(1);
+    let origin = centered(size, cell * columns, cell * 4) + Point::new(1, 1);
+    for (column, value) in values.into_iter().enumerate() {
+        for bit in 0..4 {
+            // Bit 0 on the bottom row.
+            let top_left = origin + Point::new(column as i32 * cell, (3 - bit) * cell);
+            let lit = value & (1 << bit) != 0;
+            let style = if lit {
+                PrimitiveStyle::with_fill(color)
+            } else {
+                PrimitiveStyle::with_stroke(color, 1)
+            };
+            Rectangle::new(top_left, Size::new(dot.unsigned_abs(), dot.unsigned_abs()))
+                .into_styled(style)
+                .draw(canvas)?;
+        }
+    }
+    Ok(())
+}
+
+#[allow(clippy::cast_possible_wrap)]
+fn draw_words<D>(
+    frame: &ClockScene,
+    size: Size,
+    color: Rgb888,
+    canvas: &mut D,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = Rgb888>,
+{
+    let font = Font::resolve(&frame.font);
+    let canvas_w = size.width as i32;
+    // Greedy wrap, one or more whole words per line.
+    let mut lines: Vec<String> = Vec::new();
+    for word in time_in_words(frame.now).split(' ') {
+        match lines.last_mut() {
+            Some(line) if font.text_width(&format!("{line} {word}")) <= canvas_w => {
+                line.push(' ');
+                line.push_str(word);
+            }
+            _ => lines.push(word.to_owned()),
+        }
+    }
+    let line_h = font.line_height() + 1;
+    let mut y = centered(size, 0, line_h * lines.len() as i32 - 1).y;
+    for line in &lines {
+        let x = (canvas_w - font.text_width(line)) / 2;
+        font.draw(line, Point::new(x, y), color, canvas)?;
+        y += line_h;
+    }
+    Ok(())
+}
+
+/// `time` as a word-clock phrase, rounded to the nearest five
+/// minutes: "IT IS TWENTY FIVE TO SIX".
+#[must_use]
+pub fn time_in_words(time: ClockTime) -> String {
+    const HOURS: [&str; 12] = [
+        "TWELVE", "ONE", "TWO", "THREE", "FOUR", "FIVE", "SIX", "SEVEN", "EIGHT", "NINE", "TEN",
+        "ELEVEN",
+    ];
+    let minutes = (u32::from(time.minute) * 60 + u32::from(time.second) + 150) / 300 * 5;
+    let hour = |offset: u8| HOURS[usize::from((time.hour + offset) % 12)];
+    let phrase = match minutes {
+        0 => format!("{} O'CLOCK", hour(0)),
+        60 => format!("{} O'CLOCK", hour(1)),
+        5 => format!("FIVE PAST {}", hour(0)),
+        10 => format!("TEN PAST {}", hour(0)),
+        15 => format!("QUARTER PAST {}", hour(0)),
+        20 => format!("TWENTY PAST {}", hour(0)),
+        25 => format!("TWENTY FIVE PAST {}", hour(0)),
+        30 => format!("HALF PAST {}", hour(0)),
+        35 => format!("TWENTY FIVE TO {}", hour(1)),
+        40 => format!("TWENTY TO {}", hour(1)),
+        45 => format!("QUARTER TO {}", hour(1)),
+        50 => format!("TEN TO {}", hour(1)),
+        _ => format!("FIVE TO {}", hour(1)),
+    };
+    format!("IT IS {phrase}")
+}
+
+/// `h` as shown in `format`, and whether it's AM.
+fn display_hour(format: ClockFormat, h: u8) -> (u8, bool) {
+    match format {
+        ClockFormat::H24 => (h, h < 12),
+        ClockFormat::H12 => match h {
+            0 => (12, true),
+            1..=11 => (h, true),
+            12 => (12, false),
+            _ => (h - 12, false),
+        },
+    }
+}
+
+/// `now` as text in `frame`'s format.
+fn format_time(frame: &ClockScene, now: ClockTime) -> String {
+    let (m, s) = (now.minute, now.second);
+    let (h, am) = display_hour(frame.format, now.hour);
     match frame.format {
         ClockFormat::H24 => {
             if frame.show_seconds {
@@ -155,12 +685,6 @@ fn format_time(frame: &ClockScene) -> String {
             }
         }
         ClockFormat::H12 => {
-            let (display_h, am) = match h {
-                0 => (12, true),
-                1..=11 => (h, true),
-                12 => (12, false),
-                _ => (h - 12, false),
-            };
             let suffix = if frame.show_meridiem {
                 if am {
                     "A"
@@ -171,9 +695,9 @@ fn format_time(frame: &ClockScene) -> String {
                 ""
             };
             if frame.show_seconds {
-                format!("{display_h}:{m:02}:{s:02}{suffix}")
+                format!("{h}:{m:02}:{s:02}{suffix}")
             } else {
-                format!("{display_h}:{m:02}{suffix}")
+                format!("{h}:{m:02}{suffix}")
             }
         }
     }
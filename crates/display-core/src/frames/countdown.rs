@@ -0,0 +1,250 @@
+//! Countdown mode. Renders the time left until a target instant under
+//! an optional label, then does its [`CountdownFinish`] action once
+//! the target passes. Caller supplies the wall-clock time per frame;
+//! renderer is pure.
+
+use embedded_graphics::{pixelcolor::Rgb888, prelude::*, primitives::Rectangle};
+use serde::{Deserialize, Serialize};
+
+use crate::color::Recolor;
+use crate::font::{Font, FontId};
+use crate::text::{Rgb, TextEntryColor};
+use crate::FlashState;
+
+/// Smallest unit shown. Larger units appear as needed: `1D 02:03:04`,
+/// `02:03:04`, `03:04`; `Minutes` always shows hours, `00:05`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum CountdownPrecision {
+    Minutes,
+    #[default]
+    Seconds,
+    /// `03:04.5`
+    Tenths,
+}
+
+/// What to show once the target has passed.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum CountdownFinish {
+    /// Stay at zero.
+    #[default]
+    Hold,
+    /// Stay at zero with the panel flashing, as [`crate::PanelState::flash`].
+    Flash(FlashState),
+    /// Replace the readout with this text; `\n` starts a new line.
+    Message(String),
+    /// Count up from the target, shown with a leading `+`.
+    CountUp,
+}
+
+/// Persisted shape — what the dash writes into `panels.mode_config`
+/// for countdown-mode panels. Driver constructs a `CountdownScene` per
+/// render by adding the current time.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CountdownSceneConfig {
+    /// Unix seconds to count down to. Takes precedence over
+    /// `duration_secs`.
+    #[serde(default)]
+    pub target: Option<i64>,
+    /// Timer length, counted from `started_at` (Unix seconds). For
+    /// standups and pomodoros, where the dash knows when it was
+    /// started rather than when it ends.
+    #[serde(default)]
+    pub duration_secs: Option<u64>,
+    #[serde(default)]
+    pub started_at: Option<i64>,
+    /// Drawn above the time in the default font. Empty = none.
+    #[serde(default)]
+    pub label: String,
+    #[serde(default)]
+    pub precision: CountdownPrecision,
+    #[serde(default)]
+    pub on_finish: CountdownFinish,
+    #[serde(default = "default_countdown_color")]
+    pub color: Rgb,
+    #[serde(default)]
+    pub font: FontId,
+    /// Overrides `color` with a gradient, palette, pulse or rainbow.
+    #[serde(default)]
+    pub fill: Option<TextEntryColor>,
+}
+
+impl Default for CountdownSceneConfig {
+    fn default() -> Self {
+        Self {
+            target: None,
+            duration_secs: None,
+            started_at: None,
+            label: String::new(),
+            precision: CountdownPrecision::default(),
+            on_finish: CountdownFinish::default(),
+            color: default_countdown_color(),
+            font: FontId::default(),
+            fill: None,
+        }
+    }
+}
+
+impl CountdownSceneConfig {
+    /// The instant counted down to, in Unix milliseconds. `None` when
+    /// neither a target nor a started duration is set.
+    #[must_use]
+    pub fn target_ms(&self) -> Option<i64> {
+        let target = self.target.or_else(|| {
+            let duration = i64::try_from(self.duration_secs?).ok()?;
+            self.started_at?.checked_add(duration)
+        })?;
+        target.checked_mul(1000)
+    }
+
+    /// Combine the persisted config with the current time (Unix
+    /// milliseconds) into a render-ready `CountdownScene`. With no
+    /// target set the countdown sits at zero.
+    #[must_use]
+    pub fn into_frame(self, now_ms: i64) -> CountdownScene {
+        CountdownScene {
+            remaining_ms: self
+                .target_ms()
+                .map_or(0, |target| target.saturating_sub(now_ms)),
+            label: self.label,
+            precision: self.precision,
+            on_finish: self.on_finish,
+            color: self.color,
+            font: self.font,
+            fill: self.fill,
+        }
+    }
+}
+
+fn default_countdown_color() -> Rgb {
+    Rgb {
+        r: 0x4d,
+        g: 0xd2,
+        b: 0xff,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CountdownScene {
+    /// Milliseconds to the target; negative once it has passed.
+    #[serde(default)]
+    pub remaining_ms: i64,
+    #[serde(default)]
+    pub label: String,
+    #[serde(default)]
+    pub precision: CountdownPrecision,
+    #[serde(default)]
+    pub on_finish: CountdownFinish,
+    #[serde(default = "default_countdown_color")]
+    pub color: Rgb,
+    #[serde(default)]
+    pub font: FontId,
+    #[serde(default)]
+    pub fill: Option<TextEntryColor>,
+}
+
+impl Default for CountdownScene {
+    fn default() -> Self {
+        CountdownSceneConfig::default().into_frame(0)
+    }
+}
+
+/// Pixels between the label and the time.
+const LABEL_GAP: i32 = 2;
+
+#[allow(clippy::cast_possible_wrap)]
+pub fn render<D>(frame: &CountdownScene, step: usize, canvas: &mut D) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = Rgb888> + OriginDimensions,
+{
+    let size = canvas.size();
+    let (canvas_w, canvas_h) = (size.width as i32, size.height as i32);
+    let font = Font::resolve(&frame.font);
+    let label_font = Font::resolve(&FontId::default());
+
+    let finished = frame.remaining_ms <= 0;
+    let lines: Vec<String> = match &frame.on_finish {
+        CountdownFinish::Message(message) if finished => {
+            message.split('\n').map(str::to_owned).collect()
+        }
+        CountdownFinish::CountUp if finished => {
+            vec![format!(
+                "+{}",
+                format_remaining(frame.remaining_ms.unsigned_abs(), frame.precision)
+            )]
+        }
+        _ => vec![format_remaining(
+            frame.remaining_ms.max(0).unsigned_abs(),
+            frame.precision,
+        )],
+    };
+    let has_label = !frame.label.is_empty();
+    let label_h = if has_label {
+        label_font.line_height() + LABEL_GAP
+    } else {
+        0
+    };
+    let lines_h = lines.len() as i32 * font.line_height();
+    let mut y = (canvas_h - label_h - lines_h) / 2;
+    let text_w = lines.iter().map(|l| font.text_width(l)).max().unwrap_or(0);
+    // What a fill spreads across: the time (or message), not the label.
+    let bounds = Rectangle::new(
+        Point::new((canvas_w - text_w) / 2, y + label_h),
+        Size::new(text_w.unsigned_abs(), lines_h.unsigned_abs()),
+    );
+
+    let color = frame.color.into();
+    let draw = |text: &str, font: &Font, top: i32, canvas: &mut D| -> Result<(), D::Error> {
+        let top_left = Point::new((canvas_w - font.text_width(text)) / 2, top);
+        match &frame.fill {
+            None => font.draw(text, top_left, color, canvas).map(|_| ()),
+            Some(fill) => {
+                let mut painted = Recolor {
+                    inner: canvas,
+                    paint: |p| fill.at(p, bounds, step),
+                };
+                font.draw(text, top_left, Rgb888::WHITE, &mut painted)
+                    .map(|_| ())
+            }
+        }
+    };
+    if has_label {
+        draw(&frame.label, &label_font, y, canvas)?;
+        y += label_h;
+    }
+    for line in &lines {
+        draw(line, &font, y, canvas)?;
+        y += font.line_height();
+    }
+
+    match &frame.on_finish {
+        CountdownFinish::Flash(flash) if finished => crate::draw_flash(canvas, flash, step),
+        _ => Ok(()),
+    }
+}
+
+/// `ms` as `[D ]HH:MM:SS`, dropping leading units that are zero and
+/// trailing ones finer than `precision`.
+fn format_remaining(ms: u64, precision: CountdownPrecision) -> String {
+    // Round up while counting down so `00:00` only shows at the end.
+    let tenths = ms.div_ceil(100);
+    let secs = match precision {
+        CountdownPrecision::Minutes => ms.div_ceil(60_000) * 60,
+        CountdownPrecision::Seconds => ms.div_ceil(1000),
+        CountdownPrecision::Tenths => tenths / 10,
+    };
+    let (days, hours, minutes, seconds) =
+        (secs / 86_400, secs / 3600 % 24, secs / 60 % 60, secs % 60);
+    let mut text = if days > 0 {
+        format!("{days}D {hours:02}:{minutes:02}")
+    } else if hours > 0 || precision == CountdownPrecision::Minutes {
+        format!("{hours:02}:{minutes:02}")
+    } else {
+        format!("{minutes:02}")
+    };
+    match precision {
+        CountdownPrecision::Minutes => {}
+        CountdownPrecision::Seconds => text.push_str(&format!(":{seconds:02}")),
+        CountdownPrecision::Tenths => text.push_str(&format!(":{seconds:02}.{}", tenths % 10)),
+    }
+    text
+}
@@ -0,0 +1,320 @@
+//! Gauge mode. Shows how far `value` sits between `min` and `max` —
+//! build progress, a fundraising goal, disk usage — as a bar, a ring
+//! or a semicircle dial, with the value and an optional label as text.
+//! Thresholds recolor the fill as the value climbs. Renderer is pure.
+
+use embedded_graphics::{
+    pixelcolor::Rgb888,
+    prelude::*,
+    primitives::{
+        Arc, Circle, Line, PrimitiveStyle, PrimitiveStyleBuilder, Rectangle, StrokeAlignment,
+    },
+};
+use serde::{Deserialize, Serialize};
+
+use crate::chart::{format_value, ChartThreshold};
+use crate::font::{Font, FontId};
+use crate::text::Rgb;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum GaugeStyle {
+    /// Horizontal bar filling left to right.
+    #[default]
+    Bar,
+    /// Full circle filling clockwise from the top, value in the middle.
+    Ring,
+    /// Half circle filling left to right over the top, with a needle.
+    Dial,
+}
+
+/// Persisted shape — what the dash writes into `panels.mode_config`
+/// for gauge-mode panels. The driver may also read a `source` key
+/// from the same config and take `value` from that data source.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GaugeScene {
+    #[serde(default)]
+    pub value: f32,
+    #[serde(default)]
+    pub min: f32,
+    #[serde(default = "default_max")]
+    pub max: f32,
+    #[serde(default)]
+    pub style: GaugeStyle,
+    /// Drawn in the default font. Empty = none.
+    #[serde(default)]
+    pub label: String,
+    /// Print the value under or inside the gauge.
+    #[serde(default = "default_show_value")]
+    pub show_value: bool,
+    /// Appended to the printed value: `%`, `GB`.
+    #[serde(default)]
+    pub unit: String,
+    #[serde(default = "default_gauge_color")]
+    pub color: Rgb,
+    /// The unfilled part of the gauge.
+    #[serde(default = "default_track_color")]
+    pub track_color: Rgb,
+    /// Font for the value.
+    #[serde(default)]
+    pub font: FontId,
+    /// The highest threshold at or below `value` colors the fill and
+    /// the value in place of `color`.
+    #[serde(default)]
+    pub thresholds: Vec<ChartThreshold>,
+}
+
+impl Default for GaugeScene {
+    fn default() -> Self {
+        Self {
+            value: 0.0,
+            min: 0.0,
+            max: default_max(),
+            style: GaugeStyle::default(),
+            label: String::new(),
+            show_value: default_show_value(),
+            unit: String::new(),
+            color: default_gauge_color(),
+            track_color: default_track_color(),
+            font: FontId::default(),
+            thresholds: Vec::new(),
+        }
+    }
+}
+
+fn default_max() -> f32 {
+    100.0
+}
+
+fn default_show_value() -> bool {
+    true
+}
+
+fn default_gauge_color() -> Rgb {
+    Rgb {
+        r: 0x4d,
+        g: 0xd2,
+        b: 0xff,
+    }
+}
+
+fn default_track_color() -> Rgb {
+    Rgb {
+        r: 0x20,
+        g: 0x20,
+        b: 0x20,
+    }
+}
+
+impl GaugeScene {
+    /// How full the gauge is, in [0, 1]. An empty or inverted range
+    /// reads as empty.
+    #[must_use]
+    pub fn fraction(&self) -> f32 {
+        let span = self.max - self.min;
+        if span > 0.0 && self.value.is_finite() {
+            ((self.value - self.min) / span).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    fn fill_color(&self) -> Rgb {
+        self.thresholds
+            .iter()
+            .filter(|t| t.value <= self.value)
+            .max_by(|a, b| a.value.total_cmp(&b.value))
+            .map_or(self.color, |t| t.color)
+    }
+}
+
+/// Bar thickness in pixels.
+const BAR_HEIGHT: i32 = 8;
+/// Pixels between stacked parts (label, gauge, value).
+const GAP: i32 = 2;
+
+#[allow(clippy::cast_possible_wrap)]
+pub fn render<D>(frame: &GaugeScene, _step: usize, canvas: &mut D) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = Rgb888> + OriginDimensions,
+{
+    let size = canvas.size();
+    let (canvas_w, canvas_h) = (size.width as i32, size.height as i32);
+    let text = Text {
+        font: Font::resolve(&frame.font),
+        label_font: Font::resolve(&FontId::default()),
+        fill: frame.fill_color().into(),
+        label_color: frame.color.into(),
+        canvas_w,
+    };
+    let value = if frame.show_value {
+        format!("{}{}", format_value(frame.value), frame.unit)
+    } else {
+        String::new()
+    };
+    match frame.style {
+        GaugeStyle::Bar => draw_bar(frame, &text, &value, canvas_h, canvas),
+        GaugeStyle::Ring => draw_ring(frame, &text, &value, canvas_h, canvas),
+        GaugeStyle::Dial => draw_dial(frame, &text, &value, canvas_h, canvas),
+    }
+}
+
+/// Fonts and colors for the gauge's text, and a helper to center a
+/// line of it.
+struct Text {
+    font: Font,
+    label_font: Font,
+    fill: Rgb888,
+    label_color: Rgb888,
+    canvas_w: i32,
+}
+
+impl Text {
+    /// Height of `s` in `font`, or 0 (no gap either) when it's empty.
+    fn height(s: &str, font: &Font) -> i32 {
+        if s.is_empty() {
+            0
+        } else {
+            font.line_height() + GAP
+        }
+    }
+
+    fn draw<D>(&self, s: &str, label: bool, top: i32, canvas: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Rgb888>,
+    {
+        let (font, color) = if label {
+            (&self.label_font, self.label_color)
+        } else {
+            (&self.font, self.fill)
+        };
+        let left = (self.canvas_w - font.text_width(s)) / 2;
+        font.draw(s, Point::new(left, top), color, canvas)
+            .map(|_| ())
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_precision_loss)]
+fn draw_bar<D>(
+    frame: &GaugeScene,
+    text: &Text,
+    value: &str,
+    canvas_h: i32,
+    canvas: &mut D,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = Rgb888>,
+{
+    let label_h = Text::height(&frame.label, &text.label_font);
+    let value_h = Text::height(value, &text.font);
+    let mut y = (canvas_h - label_h - BAR_HEIGHT - value_h) / 2;
+    text.draw(&frame.label, true, y, canvas)?;
+    y += label_h;
+
+    let bar_w = text.canvas_w - 2 * GAP;
+    let filled = (frame.fraction() * bar_w as f32).round() as i32;
+    let track = Rectangle::new(
+        Point::new(GAP, y),
+        Size::new(bar_w.unsigned_abs(), BAR_HEIGHT.unsigned_abs()),
+    );
+    track
+        .into_styled(PrimitiveStyle::with_fill(frame.track_color.into()))
+        .draw(canvas)?;
+    Rectangle::new(
+        track.top_left,
+        Size::new(filled.unsigned_abs(), track.size.height),
+    )
+    .into_styled(PrimitiveStyle::with_fill(text.fill))
+    .draw(canvas)?;
+    y += BAR_HEIGHT + GAP;
+
+    text.draw(value, false, y, canvas)
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn draw_ring<D>(
+    frame: &GaugeScene,
+    text: &Text,
+    value: &str,
+    canvas_h: i32,
+    canvas: &mut D,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = Rgb888>,
+{
+    let label_h = Text::height(&frame.label, &text.label_font);
+    let diameter = text.canvas_w.min(canvas_h - label_h) - 2;
+    let ring_w = (diameter / 8).max(2).unsigned_abs();
+    let center = Point::new(text.canvas_w / 2, (canvas_h - label_h) / 2);
+    let diameter = diameter.unsigned_abs();
+
+    Circle::with_center(center, diameter)
+        .into_styled(stroke(frame.track_color.into(), ring_w))
+        .draw(canvas)?;
+    let fraction = frame.fraction();
+    if fraction > 0.0 {
+        Arc::with_center(center, diameter, (-90.0).deg(), (fraction * 360.0).deg())
+            .into_styled(stroke(text.fill, ring_w))
+            .draw(canvas)?;
+    }
+
+    text.draw(value, false, center.y - text.font.line_height() / 2, canvas)?;
+    text.draw(&frame.label, true, canvas_h - label_h + GAP, canvas)
+}
+
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_precision_loss)]
+fn draw_dial<D>(
+    frame: &GaugeScene,
+    text: &Text,
+    value: &str,
+    canvas_h: i32,
+    canvas: &mut D,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = Rgb888>,
+{
+    let label_h = Text::height(&frame.label, &text.label_font);
+    let value_h = Text::height(value, &text.font);
+    // The half circle sits on its center line, text stacked below.
+    let radius = (text.canvas_w / 2).min(canvas_h - label_h - value_h - GAP) - 1;
+    let top = (canvas_h - radius - GAP - label_h - value_h) / 2;
+    let center = Point::new(text.canvas_w / 2, top + radius);
+    let diameter = (2 * radius).unsigned_abs();
+    let arc_w = (radius / 5).max(2);
+
+    Arc::with_center(center, diameter, 180.0.deg(), 180.0.deg())
+        .into_styled(stroke(frame.track_color.into(), arc_w.unsigned_abs()))
+        .draw(canvas)?;
+    let fraction = frame.fraction();
+    if fraction > 0.0 {
+        Arc::with_center(center, diameter, 180.0.deg(), (fraction * 180.0).deg())
+            .into_styled(stroke(text.fill, arc_w.unsigned_abs()))
+            .draw(canvas)?;
+    }
+    let angle = std::f32::consts::PI * (1.0 + fraction);
+    let length = (radius - arc_w - 1) as f32;
+    let tip = center
+        + Point::new(
+            (angle.cos() * length).round() as i32,
+            (angle.sin() * length).round() as i32,
+        );
+    Line::new(center, tip)
+        .into_styled(PrimitiveStyle::with_stroke(text.fill, 1))
+        .draw(canvas)?;
+
+    let mut y = center.y + GAP;
+    text.draw(value, false, y, canvas)?;
+    y += value_h;
+    text.draw(&frame.label, true, y, canvas)
+}
+
+/// A `width`-pixel stroke kept inside the shape's outline, so a ring
+/// never spills past its diameter.
+fn stroke(color: Rgb888, width: u32) -> PrimitiveStyle<Rgb888> {
+    PrimitiveStyleBuilder::new()
+        .stroke_color(color)
+        .stroke_width(width)
+        .stroke_alignment(StrokeAlignment::Inside)
+        .build()
+}
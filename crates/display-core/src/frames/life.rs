@@ -13,20 +13,22 @@ use embedded_graphics::{
 };
 use serde::{Deserialize, Serialize};
 
-use crate::text::Rgb;
+use crate::color::Recolor;
+use crate::text::{Rgb, TextEntryColor};
 
-/// 64×64 cell lattice as a flat row-major bitset (one byte per cell
-/// for cache simplicity — 4KiB total, fine for the Pi).
+/// Cell lattice, one cell per canvas pixel, as a flat row-major
+/// bitset (one byte per cell for cache simplicity — 4KiB for a 64×64
+/// panel, fine for the Pi).
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Lattice {
-    pub width: u8,
-    pub height: u8,
+    pub width: u16,
+    pub height: u16,
     pub cells: Vec<u8>,
 }
 
 impl Lattice {
     #[must_use]
-    pub fn new(width: u8, height: u8) -> Self {
+    pub fn new(width: u16, height: u16) -> Self {
         Self {
             width,
             height,
@@ -119,6 +121,10 @@ pub struct LifeSceneConfig {
     /// generations. Clamped to >= 1 by the renderer.
     #[serde(default = "default_step_interval_frames")]
     pub step_interval_frames: u32,
+    /// Overrides `color` with a gradient, palette, pulse or rainbow
+    /// spread across the whole canvas.
+    #[serde(default)]
+    pub fill: Option<TextEntryColor>,
 }
 
 impl Default for LifeSceneConfig {
@@ -126,6 +132,7 @@ impl Default for LifeSceneConfig {
         Self {
             color: default_life_color(),
             step_interval_frames: DEFAULT_STEP_INTERVAL_FRAMES,
+            fill: None,
         }
     }
 }
@@ -137,6 +144,7 @@ impl LifeSceneConfig {
     pub fn into_frame(self, lattice: &Lattice) -> LifeScene {
         LifeScene {
             color: self.color,
+            fill: self.fill,
             lattice_width: lattice.width,
             lattice_height: lattice.height,
             cells: lattice.cells.clone(),
@@ -151,9 +159,12 @@ impl LifeSceneConfig {
 pub struct LifeScene {
     /// Live-cell color.
     pub color: Rgb,
+    /// Overrides `color`; see [`LifeSceneConfig::fill`].
+    #[serde(default)]
+    pub fill: Option<TextEntryColor>,
     /// Current lattice (caller advances between frames).
-    pub lattice_width: u8,
-    pub lattice_height: u8,
+    pub lattice_width: u16,
+    pub lattice_height: u16,
     pub cells: Vec<u8>,
 }
 
@@ -161,6 +172,7 @@ impl Default for LifeScene {
     fn default() -> Self {
         Self {
             color: default_life_color(),
+            fill: None,
             lattice_width: 64,
             lattice_height: 64,
             cells: vec![0; 64 * 64],
@@ -169,9 +181,24 @@ impl Default for LifeScene {
 }
 
 #[allow(clippy::cast_possible_wrap)]
-pub fn render<D>(frame: &LifeScene, canvas: &mut D) -> Result<(), D::Error>
+pub fn render<D>(frame: &LifeScene, step: usize, canvas: &mut D) -> Result<(), D::Error>
 where
     D: DrawTarget<Color = Rgb888> + OriginDimensions,
+{
+    if let Some(fill) = &frame.fill {
+        let bounds = Rectangle::new(Point::zero(), canvas.size());
+        let mut painted = Recolor {
+            inner: canvas,
+            paint: |p| fill.at(p, bounds, step),
+        };
+        return draw_cells(frame, &mut painted);
+    }
+    draw_cells(frame, canvas)
+}
+
+fn draw_cells<D>(frame: &LifeScene, canvas: &mut D) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = Rgb888>,
 {
     let style = PrimitiveStyleBuilder::new()
         .fill_color(frame.color.into())
@@ -1,11 +1,16 @@
 //! Scene renderers, one module per [`crate::Mode`] variant.
 
 pub mod boot;
+pub mod chart;
 pub mod clock;
+pub mod countdown;
+pub mod gauge;
 pub mod gif;
 pub mod image;
 pub mod life;
+pub mod playlist;
 pub mod setup;
 pub mod shapes;
 pub mod test;
 pub mod text;
+pub mod weather;
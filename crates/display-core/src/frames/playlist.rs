@@ -0,0 +1,104 @@
+//! Playlist mode. Cycles through an ordered list of child scenes,
+//! each shown for its own number of steps, then wraps around.
+//!
+//! Each child sees a step counter that restarts at zero when its slot
+//! begins, so a marquee or GIF always plays from the top instead of
+//! picking up wherever the global counter happens to be. Children
+//! can't themselves be playlists — a nested `Mode::Playlist` renders
+//! blank.
+
+use embedded_graphics::{pixelcolor::Rgb888, prelude::*};
+use serde::{Deserialize, Serialize};
+
+use crate::{BrightnessTarget, Mode};
+
+/// How an item enters and leaves the screen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum PlaylistTransition {
+    /// Hard switch to the next item.
+    #[default]
+    Cut,
+    /// Ramp up from black over the first `steps` steps of the item
+    /// and back down over its last `steps`. Capped at half the item's
+    /// duration.
+    Fade { steps: usize },
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PlaylistItem {
+    pub mode: Mode,
+    /// How long the item stays up, in render steps. Zero-length items
+    /// are skipped.
+    pub duration_steps: usize,
+    #[serde(default)]
+    pub transition: PlaylistTransition,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PlaylistScene {
+    pub items: Vec<PlaylistItem>,
+}
+
+impl PlaylistScene {
+    /// The item showing at global `step`, with the step relative to
+    /// the start of that item. `None` for an empty playlist (or one
+    /// where every item has zero duration).
+    #[must_use]
+    pub fn active(&self, step: usize) -> Option<(usize, usize)> {
+        let total: usize = self.items.iter().map(|i| i.duration_steps).sum();
+        if total == 0 {
+            return None;
+        }
+        let mut offset = step % total;
+        for (index, item) in self.items.iter().enumerate() {
+            if offset < item.duration_steps {
+                return Some((index, offset));
+            }
+            offset -= item.duration_steps;
+        }
+        unreachable!("offset is below the summed durations")
+    }
+}
+
+pub fn render<D>(scene: &PlaylistScene, step: usize, canvas: &mut D) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = Rgb888> + OriginDimensions,
+{
+    let Some((index, local_step)) = scene.active(step) else {
+        return Ok(());
+    };
+    let item = &scene.items[index];
+    let scale = transition_scale(item.transition, item.duration_steps, local_step);
+    if scale >= 0.999 {
+        crate::render_mode(&item.mode, local_step, canvas)
+    } else {
+        let mut faded = BrightnessTarget {
+            inner: canvas,
+            scale,
+            dither: None,
+        };
+        crate::render_mode(&item.mode, local_step, &mut faded)
+    }
+}
+
+/// Brightness multiplier for `local_step` of an item lasting
+/// `duration` steps.
+#[allow(clippy::cast_precision_loss)]
+fn transition_scale(transition: PlaylistTransition, duration: usize, local_step: usize) -> f32 {
+    match transition {
+        PlaylistTransition::Cut => 1.0,
+        PlaylistTransition::Fade { steps } => {
+            let steps = steps.min(duration / 2);
+            if steps == 0 {
+                return 1.0;
+            }
+            // Distance to the nearer edge of the item's slot.
+            let edge = local_step.min(duration - 1 - local_step);
+            if edge >= steps {
+                1.0
+            } else {
+                (edge + 1) as f32 / (steps + 1) as f32
+            }
+        }
+    }
+}
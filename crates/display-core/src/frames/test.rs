@@ -24,6 +24,13 @@ pub enum TestPattern {
     /// 1×1 checkerboard. White squares at every other (x+y) position.
     /// Surfaces row-driver shadows and moiré with the camera grid.
     Checkerboard,
+    /// Chart for tuning [`crate::calibration::Calibration`] by eye.
+    /// Top half: eight grey steps from black to white — they should
+    /// look neutral and evenly spaced (gain, gamma). Next quarter:
+    /// near-black greys 0–12 — the first should be dark and the rest
+    /// visible (black level). Bottom quarter: the dash's LED orange
+    /// beside white, to match across panels.
+    Calibration,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Default, Deserialize, Serialize)]
@@ -45,6 +52,7 @@ where
         TestPattern::ColorBars => render_color_bars(canvas, w, h)?,
         TestPattern::Gradient => render_gradient(canvas, w, h)?,
         TestPattern::Checkerboard => render_checkerboard(canvas, w, h)?,
+        TestPattern::Calibration => render_calibration(canvas, w, h)?,
     }
     Ok(())
 }
@@ -139,3 +147,46 @@ where
     }
     Ok(())
 }
+
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_sign_loss)]
+fn render_calibration<D>(canvas: &mut D, w: i32, h: i32) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = Rgb888> + OriginDimensions,
+{
+    const NEAR_BLACK: [u8; 8] = [0, 1, 2, 3, 4, 6, 8, 12];
+    const ORANGE: Rgb888 = Rgb888::new(255, 138, 44);
+    let fill = |canvas: &mut D, x: i32, y: i32, width: i32, height: i32, color: Rgb888| {
+        Rectangle::new(Point::new(x, y), Size::new(width as u32, height as u32))
+            .into_styled(PrimitiveStyleBuilder::new().fill_color(color).build())
+            .draw(canvas)
+    };
+
+    let step_w = w / 8;
+    let (ramp_h, dark_h) = (h / 2, h / 4);
+    for i in 0..8 {
+        let x = i * step_w;
+        let width = if i == 7 { w - x } else { step_w };
+        let grey = (i * 255 / 7) as u8;
+        fill(canvas, x, 0, width, ramp_h, Rgb888::new(grey, grey, grey))?;
+        let dark = NEAR_BLACK[i as usize];
+        fill(
+            canvas,
+            x,
+            ramp_h,
+            width,
+            dark_h,
+            Rgb888::new(dark, dark, dark),
+        )?;
+    }
+    let swatch_y = ramp_h + dark_h;
+    fill(canvas, 0, swatch_y, w / 2, h - swatch_y, ORANGE)?;
+    fill(
+        canvas,
+        w / 2,
+        swatch_y,
+        w - w / 2,
+        h - swatch_y,
+        Rgb888::WHITE,
+    )
+}
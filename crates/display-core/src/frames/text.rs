@@ -1,16 +1,38 @@
 //! Text-sign mode. Renders an ordered list of text entries, each with
-//! its own color (solid or rainbow) and optional marquee scroll.
+//! its own color (solid, rainbow, or one of the [`crate::color`]
+//! effects) and optional marquee scroll.
 //! Lines are stacked top-to-bottom in `panel.scroll`-shifted positions
-//! so the panel can scroll through more entries than fit at once.
+//! so the panel can scroll through more entries than fit at once; each
+//! line is as tall as its entry's font plus [`TextLayout::line_spacing`].
+//!
+//! An entry becomes one line per `\n`-separated paragraph, and with
+//! [`TextEntryOptions::wrap`] each paragraph is further broken between
+//! words to fit the panel width. Lines are aligned horizontally per
+//! entry, and the whole block vertically per [`TextLayout`].
+//!
+//! With [`TextEntryOptions::markup`] on, an entry's text can style
+//! individual words — `{red}ALERT{/} build {green,bold}passing{/}`.
+//!
+//! When the lines don't all fit, [`TextLayout::auto_scroll`] can cycle
+//! through them on its own, either as a continuous upward crawl or a
+//! page at a time.
+//!
+//! Each entry can also loop an entrance [`TextEffect`] (typewriter,
+//! fade, scroll-in), and its marquee can run either way or bounce.
+
+use std::borrow::Cow;
+use std::ops::Range;
 
 use embedded_graphics::{
-    mono_font::{ascii::FONT_5X8, MonoTextStyle, MonoTextStyleBuilder},
     pixelcolor::Rgb888,
     prelude::*,
-    text::Text,
+    primitives::{PrimitiveStyle, Rectangle},
 };
 use serde::{Deserialize, Serialize};
 
+use crate::color::{GradientOptions, PaletteOptions, PulseOptions, Recolor};
+use crate::font::{Font, FontId};
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct Rgb {
     pub r: u8,
@@ -34,17 +56,175 @@ pub struct RainbowOptions {
 pub enum TextEntryColor {
     Rgb(Rgb),
     Rainbow(RainbowOptions),
+    Gradient(GradientOptions),
+    Palette(PaletteOptions),
+    Pulse(PulseOptions),
+}
+
+impl TextEntryColor {
+    /// Color at `point` of a shape spanning `bounds`, at `step`. For
+    /// text, per-letter rainbows are colored by letter instead (see
+    /// [`render`]); anywhere else they spread across `bounds` like a
+    /// horizontal gradient.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_possible_wrap)]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn at(&self, point: Point, bounds: Rectangle, step: usize) -> Rgb888 {
+        match self {
+            Self::Rgb(rgb) => (*rgb).into(),
+            Self::Rainbow(RainbowOptions {
+                is_per_letter: false,
+                speed,
+            }) => rainbow_color((step * *speed as usize / 10) % (255 * 3)),
+            Self::Rainbow(RainbowOptions {
+                is_per_letter: true,
+                speed,
+            }) => {
+                let offset = (point.x - bounds.top_left.x).max(0) as usize;
+                let width = (bounds.size.width as usize).max(1);
+                letter_rainbow(*speed, offset, width, step)
+            }
+            Self::Gradient(gradient) => gradient.at(point, bounds, step),
+            Self::Palette(palette) => palette.at(step),
+            Self::Pulse(pulse) => pulse.at(step),
+        }
+    }
 }
 
 #[derive(PartialEq, Eq, Clone, Debug, Deserialize, Serialize)]
 pub struct MarqueeOptions {
     pub speed: u32,
+    /// Missing on entries written before bounce and left-to-right.
+    #[serde(default)]
+    pub direction: MarqueeDirection,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum MarqueeDirection {
+    /// Enter from the right edge, leave off the left, loop.
+    #[default]
+    RightToLeft,
+    LeftToRight,
+    /// Ping-pong between left- and right-aligned, for text only a
+    /// little wider (or narrower) than the panel.
+    Bounce,
+}
+
+/// Animation applied to an entry on top of its color and marquee.
+/// Each one loops, restarting once its `hold_steps` run out, so a
+/// long-running sign replays the entrance rather than showing it once.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum TextEffect {
+    #[default]
+    None,
+    /// Reveal one character every `steps_per_char`, across all of the
+    /// entry's lines, then hold the full text for `hold_steps` (at
+    /// least one character's worth) before starting over.
+    Typewriter {
+        steps_per_char: usize,
+        #[serde(default)]
+        hold_steps: usize,
+    },
+    /// Fade up from black over `fade_steps`, hold, fade back out.
+    Fade {
+        fade_steps: usize,
+        #[serde(default)]
+        hold_steps: usize,
+    },
+    /// Slide up into place from below the line over `steps`, clipped to
+    /// the line's own row.
+    ScrollIn {
+        steps: usize,
+        #[serde(default)]
+        hold_steps: usize,
+    },
 }
 
 #[derive(PartialEq, Eq, Clone, Debug, Deserialize, Serialize)]
 pub struct TextEntryOptions {
     pub color: TextEntryColor,
     pub marquee: MarqueeOptions,
+    /// Missing on entries written before font selection existed.
+    #[serde(default)]
+    pub font: FontId,
+    /// Ignored while the marquee is running.
+    #[serde(default)]
+    pub align: HorizontalAlign,
+    /// Break lines between words to fit the panel width. Ignored
+    /// while the marquee is running, which scrolls the whole line.
+    #[serde(default)]
+    pub wrap: bool,
+    /// Parse inline style tags in `text`; see [`parse_markup`]. Off,
+    /// braces are drawn as-is.
+    #[serde(default)]
+    pub markup: bool,
+    #[serde(default)]
+    pub effect: TextEffect,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum HorizontalAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum VerticalAlign {
+    #[default]
+    Top,
+    Middle,
+    Bottom,
+}
+
+/// Block-level layout — the text-mode `mode_config`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TextLayout {
+    /// Where the block of lines sits when it's shorter than the
+    /// panel. A block that overflows always starts at the top.
+    #[serde(default)]
+    pub vertical_align: VerticalAlign,
+    /// Pixels between consecutive lines.
+    #[serde(default = "default_line_spacing")]
+    pub line_spacing: i32,
+    /// Only kicks in when the lines overflow the panel.
+    #[serde(default)]
+    pub auto_scroll: AutoScroll,
+}
+
+/// How overflowing text cycles through its lines, driven by `step`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum AutoScroll {
+    /// Static; lines past the bottom edge stay hidden.
+    #[default]
+    Off,
+    /// Crawl upward, same units as the marquee (`speed / 10` pixels
+    /// per step), wrapping round to the first line after a blank
+    /// `gap` of pixels.
+    Smooth {
+        speed: u32,
+        #[serde(default)]
+        gap: i32,
+    },
+    /// Show as many whole lines as fit for `dwell_steps`, then flip to
+    /// the next page.
+    Page { dwell_steps: usize },
+}
+
+fn default_line_spacing() -> i32 {
+    1
+}
+
+impl Default for TextLayout {
+    fn default() -> Self {
+        Self {
+            vertical_align: VerticalAlign::default(),
+            line_spacing: default_line_spacing(),
+            auto_scroll: AutoScroll::default(),
+        }
+    }
 }
 
 #[derive(PartialEq, Eq, Clone, Debug, Deserialize, Serialize)]
@@ -62,118 +242,489 @@ pub struct TextScene {
     /// index are not drawn (they've scrolled off the top).
     #[serde(default)]
     pub scroll: i32,
+    #[serde(default)]
+    pub layout: TextLayout,
+}
+
+/// An entry ready to lay out: its text with any markup stripped, and
+/// the style of each run of it.
+struct Styled<'a> {
+    entry: &'a TextEntry,
+    text: Cow<'a, str>,
+    /// `(byte offset, style)` in order; each run lasts until the next.
+    runs: Vec<(usize, SpanStyle)>,
+    /// Characters in `text`, for per-letter effects.
+    char_count: usize,
+    font: Font,
 }
 
+impl<'a> Styled<'a> {
+    fn new(entry: &'a TextEntry) -> Self {
+        let (text, runs) = if entry.options.markup {
+            let mut text = String::new();
+            let mut runs = Vec::new();
+            for span in parse_markup(&entry.text) {
+                runs.push((text.len(), span.style));
+                text.push_str(&span.text);
+            }
+            (Cow::Owned(text), runs)
+        } else {
+            (Cow::Borrowed(entry.text.as_str()), Vec::new())
+        };
+        Self {
+            entry,
+            char_count: text.chars().count(),
+            text,
+            runs,
+            font: Font::resolve(&entry.options.font),
+        }
+    }
+
+    fn style_at(&self, index: usize) -> SpanStyle {
+        let run = self.runs.partition_point(|&(start, _)| start <= index);
+        run.checked_sub(1)
+            .map_or_else(SpanStyle::default, |run| self.runs[run].1)
+    }
+
+    /// Advance of the character at byte `index`; bold adds a pixel.
+    fn char_width(&self, index: usize, c: char) -> i32 {
+        let width = self.font.char_width(c);
+        width + i32::from(width > 0 && self.style_at(index).bold)
+    }
+
+    fn width(&self, range: Range<usize>) -> i32 {
+        let start = range.start;
+        self.text[range]
+            .char_indices()
+            .map(|(i, c)| self.char_width(start + i, c))
+            .sum()
+    }
+}
+
+/// One laid-out row: all or part of an entry's text.
+struct Line<'a> {
+    styled: &'a Styled<'a>,
+    /// Byte range of `styled.text`.
+    range: Range<usize>,
+}
+
+impl Line<'_> {
+    fn height(&self) -> i32 {
+        self.styled.font.line_height()
+    }
+}
+
+/// Half-period of `{blink}`, in steps.
+const BLINK_STEPS: usize = 30;
+
 #[allow(clippy::cast_possible_truncation)]
 #[allow(clippy::cast_possible_wrap)]
-#[allow(clippy::cast_sign_loss)]
-#[allow(clippy::cast_lossless)]
-#[allow(clippy::cast_precision_loss)]
 pub fn render<D>(frame: &TextScene, step: usize, canvas: &mut D) -> Result<(), D::Error>
 where
     D: DrawTarget<Color = Rgb888> + OriginDimensions,
 {
-    let font = FONT_5X8;
-    let canvas_w = canvas.size().width as i32;
-
-    for (i, entry) in frame.entries.iter().enumerate() {
-        let marquee_offset = compute_marquee_offset(entry, step, canvas_w, &font);
-        let line_offset =
-            (i as i32 + 1 - frame.scroll) * (font.character_size.height as i32 + 1) - 1;
-
-        match &entry.options.color {
-            TextEntryColor::Rgb(rgb) => {
-                draw_text(
-                    canvas,
-                    &entry.text,
-                    Point::new(marquee_offset, line_offset),
-                    style(&font, rgb.clone().into()),
-                )?;
-            }
-            TextEntryColor::Rainbow(RainbowOptions {
-                is_per_letter: true,
-                speed,
-            }) => {
-                for (j, c) in entry.text.chars().enumerate() {
-                    let char_offset = j as i32
-                        * (font.character_size.width as i32 + font.character_spacing as i32);
-                    let progress = (((255 * 3) as f64
-                        * (j as f64 / entry.text.chars().count().max(1) as f64))
-                        as usize
-                        + ((step * *speed as usize / 10) % (255 * 3)))
-                        % (255 * 3);
-                    let s = c.to_string();
-                    draw_text(
-                        canvas,
-                        &s,
-                        Point::new(marquee_offset + char_offset, line_offset),
-                        style(&font, rainbow_color(progress)),
-                    )?;
-                }
-            }
-            TextEntryColor::Rainbow(RainbowOptions {
-                is_per_letter: false,
-                speed,
-            }) => {
-                let progress = (step * *speed as usize / 10) % (255 * 3);
-                draw_text(
-                    canvas,
-                    &entry.text,
-                    Point::new(marquee_offset, line_offset),
-                    style(&font, rainbow_color(progress)),
-                )?;
+    let size = canvas.size();
+    let (canvas_w, canvas_h) = (size.width as i32, size.height as i32);
+    let spacing = frame.layout.line_spacing;
+
+    // Entries before `scroll` have scrolled off the top. A negative
+    // scroll pushes the list down by that many default-font lines.
+    let lead = -frame.scroll.min(0) * (Font::resolve(&FontId::default()).line_height() + spacing);
+    let styled: Vec<Styled> = frame
+        .entries
+        .iter()
+        .skip(usize::try_from(frame.scroll).unwrap_or(0))
+        .map(Styled::new)
+        .collect();
+    let lines = layout_lines(&styled, canvas_w);
+    let visible_h = canvas_h - lead;
+    let block_h = block_height(&lines, spacing);
+    let align = |block_h: i32| {
+        let free = (visible_h - block_h).max(0);
+        lead + match frame.layout.vertical_align {
+            VerticalAlign::Top => 0,
+            VerticalAlign::Middle => free / 2,
+            VerticalAlign::Bottom => free,
+        }
+    };
+
+    // Each line with the top row it's drawn at.
+    let placed: Vec<(&Line, i32)> = match frame.layout.auto_scroll {
+        AutoScroll::Smooth { speed, gap } if block_h > visible_h => {
+            let period = (block_h + spacing + gap.max(0)).max(1);
+            let offset = (step as i64 * i64::from(speed) / 10 % i64::from(period)) as i32;
+            // The tail of one pass and the head of the next.
+            let first = stack(&lines, lead - offset, spacing);
+            let second = stack(&lines, lead - offset + period, spacing);
+            first.chain(second).collect()
+        }
+        AutoScroll::Page { dwell_steps } if block_h > visible_h => {
+            let pages = paginate(&lines, spacing, visible_h);
+            let page = &lines[pages[step / dwell_steps.max(1) % pages.len()].clone()];
+            stack(page, align(block_height(page, spacing)), spacing).collect()
+        }
+        _ => stack(&lines, align(block_h), spacing).collect(),
+    };
+
+    for (line, line_top) in placed {
+        if line_top >= canvas_h || line_top + line.height() <= 0 {
+            continue;
+        }
+        let entry = line.styled.entry;
+        let text_w = line.styled.width(line.range.clone());
+        let x = if entry.options.marquee.speed == 0 {
+            match entry.options.align {
+                HorizontalAlign::Left => 0,
+                HorizontalAlign::Center => (canvas_w - text_w) / 2,
+                HorizontalAlign::Right => canvas_w - text_w,
             }
+        } else {
+            compute_marquee_offset(entry, text_w, step, canvas_w)
+        };
+        let drop = entry.options.effect.drop(line.height(), step);
+        if drop == 0 {
+            draw_line(line, Point::new(x, line_top), step, canvas)?;
+        } else {
+            let row = Rectangle::new(
+                Point::new(0, line_top),
+                Size::new(size.width, line.height().unsigned_abs()),
+            );
+            draw_line(
+                line,
+                Point::new(x, line_top + drop),
+                step,
+                &mut canvas.clipped(&row),
+            )?;
         }
     }
 
     Ok(())
 }
 
-fn style<'a>(
-    font: &'a embedded_graphics::mono_font::MonoFont<'a>,
-    color: Rgb888,
-) -> MonoTextStyle<'a, Rgb888> {
-    MonoTextStyleBuilder::new()
-        .font(font)
-        .text_color(color)
-        .build()
-}
-
-fn draw_text<D>(
-    canvas: &mut D,
-    text: &str,
-    point: Point,
-    style: MonoTextStyle<'_, Rgb888>,
-) -> Result<(), D::Error>
+/// Draw `line` from `origin` one character at a time, each in its
+/// span's style.
+fn draw_line<D>(line: &Line, origin: Point, step: usize, canvas: &mut D) -> Result<(), D::Error>
 where
     D: DrawTarget<Color = Rgb888>,
 {
-    Text::new(text, point, style).draw(canvas)?;
+    let styled = line.styled;
+    let font = &styled.font;
+    let blink_on = (step / BLINK_STEPS).is_multiple_of(2);
+    let effect = styled.entry.options.effect;
+    let revealed = effect.revealed(styled.char_count, step);
+    let level = effect.level(step);
+    // Characters before this line, for per-letter effects that run on
+    // across lines.
+    let first_char = styled.text[..line.range.start].chars().count();
+    // What gradients spread across.
+    let bounds = Rectangle::new(
+        origin,
+        Size::new(
+            styled.width(line.range.clone()).unsigned_abs(),
+            font.line_height().unsigned_abs(),
+        ),
+    );
+    let mut x = origin.x;
+    for (j, (i, c)) in styled.text[line.range.clone()].char_indices().enumerate() {
+        let index = line.range.start + i;
+        let style = styled.style_at(index);
+        let advance = styled.char_width(index, c);
+        if (style.blink && !blink_on) || first_char + j >= revealed {
+            x += advance;
+            continue;
+        }
+        // Per-letter colors are fixed for the glyph; the rest are
+        // sampled per pixel across the line.
+        let letter = match style.color {
+            Some(SpanColor::Rgb(rgb)) => Some(rgb.into()),
+            Some(SpanColor::Rainbow) => {
+                Some(letter_rainbow(10, first_char + j, styled.char_count, step))
+            }
+            None => match &styled.entry.options.color {
+                TextEntryColor::Rainbow(RainbowOptions {
+                    is_per_letter: true,
+                    speed,
+                }) => Some(letter_rainbow(
+                    *speed,
+                    first_char + j,
+                    styled.char_count,
+                    step,
+                )),
+                _ => None,
+            },
+        };
+        let mut painted = Recolor {
+            inner: &mut *canvas,
+            paint: |p| {
+                let color =
+                    letter.unwrap_or_else(|| styled.entry.options.color.at(p, bounds, step));
+                dim(color, level)
+            },
+        };
+        let cell = Point::new(x, origin.y);
+        let mut buf = [0; 4];
+        let glyph = c.encode_utf8(&mut buf);
+        if style.inverse {
+            Rectangle::new(
+                cell,
+                Size::new(advance.unsigned_abs(), font.line_height().unsigned_abs()),
+            )
+            .into_styled(PrimitiveStyle::with_fill(Rgb888::WHITE))
+            .draw(&mut painted)?;
+            font.draw(glyph, cell, Rgb888::BLACK, canvas)?;
+            if style.bold {
+                font.draw(glyph, cell + Point::new(1, 0), Rgb888::BLACK, canvas)?;
+            }
+        } else {
+            font.draw(glyph, cell, Rgb888::WHITE, &mut painted)?;
+            if style.bold {
+                font.draw(glyph, cell + Point::new(1, 0), Rgb888::WHITE, &mut painted)?;
+            }
+        }
+        x += advance;
+    }
     Ok(())
 }
 
+/// Rainbow color of letter `index` of `total`, cycling at `speed`.
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_sign_loss)]
+#[allow(clippy::cast_precision_loss)]
+fn letter_rainbow(speed: u32, index: usize, total: usize, step: usize) -> Rgb888 {
+    let progress = (((255 * 3) as f64 * (index as f64 / total.max(1) as f64)) as usize
+        + ((step * speed as usize / 10) % (255 * 3)))
+        % (255 * 3);
+    rainbow_color(progress)
+}
+
+/// Total height of `lines` stacked with `spacing` between them.
+fn block_height(lines: &[Line], spacing: i32) -> i32 {
+    let heights: i32 = lines.iter().map(|l| l.height() + spacing).sum();
+    if lines.is_empty() {
+        0
+    } else {
+        heights - spacing
+    }
+}
+
+/// `lines` stacked downward from `top`, each with its top row.
+fn stack<'a, 'b>(
+    lines: &'b [Line<'a>],
+    top: i32,
+    spacing: i32,
+) -> impl Iterator<Item = (&'b Line<'a>, i32)> {
+    lines.iter().scan(top, move |next, line| {
+        let top = *next;
+        *next += line.height() + spacing;
+        Some((line, top))
+    })
+}
+
+/// Split `lines` into runs of whole lines that each fit in `height`.
+/// A line taller than `height` gets a page to itself.
+fn paginate(lines: &[Line], spacing: i32, height: i32) -> Vec<Range<usize>> {
+    let mut pages = Vec::new();
+    let mut start = 0;
+    let mut used = 0;
+    for (i, line) in lines.iter().enumerate() {
+        let line_h = line.height();
+        if i > start && used + spacing + line_h > height {
+            pages.push(start..i);
+            start = i;
+            used = 0;
+        }
+        used += if i == start { line_h } else { spacing + line_h };
+    }
+    pages.push(start..lines.len());
+    pages
+}
+
+/// Break every entry into the lines it occupies, in order.
+fn layout_lines<'a>(styled: &'a [Styled<'a>], canvas_w: i32) -> Vec<Line<'a>> {
+    let mut lines = Vec::new();
+    for entry in styled {
+        let options = &entry.entry.options;
+        let wrap = options.wrap && options.marquee.speed == 0;
+        let mut start = 0;
+        for paragraph in entry.text.split('\n') {
+            let range = start..start + paragraph.len();
+            for range in break_paragraph(entry, range, canvas_w, wrap) {
+                lines.push(Line {
+                    styled: entry,
+                    range,
+                });
+            }
+            start += paragraph.len() + 1;
+        }
+    }
+    lines
+}
+
+/// Split one paragraph (a byte range of `styled.text`) into lines no
+/// wider than `width`, breaking after whitespace where possible and
+/// mid-word only when a single word doesn't fit. Whitespace at a break
+/// is dropped. Without `wrap`, the paragraph is one line.
+fn break_paragraph(
+    styled: &Styled,
+    paragraph: Range<usize>,
+    width: i32,
+    wrap: bool,
+) -> Vec<Range<usize>> {
+    if !wrap {
+        return vec![paragraph];
+    }
+    let text = styled.text.as_ref();
+    let trimmed = |range: Range<usize>| range.start..range.start + text[range].trim_end().len();
+    let mut lines = Vec::new();
+    let mut start = paragraph.start;
+    let mut line_w = 0;
+    // Byte offset of the last whitespace on the current line.
+    let mut last_space = None;
+    for (i, c) in text[paragraph.clone()].char_indices() {
+        let i = paragraph.start + i;
+        let w = styled.char_width(i, c);
+        if c.is_whitespace() {
+            if i == start && start > paragraph.start {
+                // Leading whitespace on a wrapped line.
+                start = i + c.len_utf8();
+            } else if line_w + w > width {
+                lines.push(trimmed(start..i));
+                start = i + c.len_utf8();
+                line_w = 0;
+                last_space = None;
+            } else {
+                last_space = Some(i);
+                line_w += w;
+            }
+            continue;
+        }
+        if line_w + w > width && i > start {
+            let end = last_space.unwrap_or(i);
+            lines.push(trimmed(start..end));
+            start = if end == i {
+                i
+            } else {
+                end + text[end..].chars().next().map_or(0, char::len_utf8)
+            };
+            line_w = styled.width(start..i);
+            last_space = None;
+        }
+        line_w += w;
+    }
+    if start < paragraph.end || lines.is_empty() {
+        lines.push(start..paragraph.end);
+    }
+    lines
+}
+
 #[allow(clippy::cast_possible_truncation)]
 #[allow(clippy::cast_possible_wrap)]
-fn compute_marquee_offset(
-    entry: &TextEntry,
-    step: usize,
-    canvas_w: i32,
-    font: &embedded_graphics::mono_font::MonoFont<'_>,
-) -> i32 {
-    if entry.options.marquee.speed == 0 {
+fn compute_marquee_offset(entry: &TextEntry, text_size: i32, step: usize, canvas_w: i32) -> i32 {
+    let marquee = &entry.options.marquee;
+    if marquee.speed == 0 {
         return 0;
     }
-    let text_size: i32 = entry.text.chars().count() as i32
-        * (font.character_size.width + font.character_spacing) as i32;
-    let raw =
-        (step as i32 * entry.options.marquee.speed as i32 / 10) % (text_size + canvas_w).max(1);
-    if raw < text_size {
+    let travelled = (step as u64 * u64::from(marquee.speed) / 10) as i64;
+    if marquee.direction == MarqueeDirection::Bounce {
+        // Between left-aligned (0) and right-aligned, whichever side
+        // of 0 that is.
+        let end = canvas_w - text_size;
+        let span = i64::from(end.abs());
+        if span == 0 {
+            return 0;
+        }
+        let phase = travelled % (2 * span);
+        let offset = (if phase <= span {
+            phase
+        } else {
+            2 * span - phase
+        }) as i32;
+        return offset * end.signum();
+    }
+    let raw = (travelled % i64::from((text_size + canvas_w).max(1))) as i32;
+    let x = if raw < text_size {
         -raw
     } else {
         -raw + canvas_w + text_size
+    };
+    match marquee.direction {
+        MarqueeDirection::LeftToRight => canvas_w - text_size - x,
+        _ => x,
     }
 }
 
+impl TextEffect {
+    /// Characters of an entry of `chars` shown at `step`.
+    fn revealed(self, chars: usize, step: usize) -> usize {
+        match self {
+            Self::Typewriter {
+                steps_per_char,
+                hold_steps,
+            } => {
+                let steps_per_char = steps_per_char.max(1);
+                // The full text stays up for at least one character's
+                // steps, so a zero hold still shows the last character.
+                let period = chars * steps_per_char + hold_steps.max(steps_per_char);
+                step % period / steps_per_char
+            }
+            _ => usize::MAX,
+        }
+    }
+
+    /// Brightness in [0, 1] at `step`.
+    #[allow(clippy::cast_precision_loss)]
+    fn level(self, step: usize) -> f32 {
+        match self {
+            Self::Fade {
+                fade_steps,
+                hold_steps,
+            } if fade_steps > 0 => {
+                let period = 2 * fade_steps + hold_steps;
+                let elapsed = step % period;
+                let lit = if elapsed < fade_steps {
+                    elapsed
+                } else if elapsed < fade_steps + hold_steps {
+                    fade_steps
+                } else {
+                    period - elapsed
+                };
+                lit as f32 / fade_steps as f32
+            }
+            _ => 1.0,
+        }
+    }
+
+    /// Pixels below its resting row that a line `height` tall is drawn
+    /// at `step`.
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_possible_wrap)]
+    fn drop(self, height: i32, step: usize) -> i32 {
+        match self {
+            Self::ScrollIn { steps, hold_steps } if steps > 0 => {
+                let elapsed = step % (steps + hold_steps);
+                if elapsed >= steps {
+                    return 0;
+                }
+                // Round up so the line is only in place once `steps` is up.
+                let remaining = (steps - elapsed) as u64;
+                (u64::from(height.unsigned_abs()) * remaining).div_ceil(steps as u64) as i32
+            }
+            _ => 0,
+        }
+    }
+}
+
+/// `color` scaled by `level` in [0, 1].
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_sign_loss)]
+fn dim(color: Rgb888, level: f32) -> Rgb888 {
+    if level >= 1.0 {
+        return color;
+    }
+    let scale = |c: u8| (f32::from(c) * level).round() as u8;
+    Rgb888::new(scale(color.r()), scale(color.g()), scale(color.b()))
+}
+
 #[allow(clippy::cast_possible_truncation)]
 fn rainbow_color(progress: usize) -> Rgb888 {
     if progress < 255 {
@@ -188,3 +739,138 @@ fn rainbow_color(progress: usize) -> Rgb888 {
         )
     }
 }
+
+/* ─── markup ─────────────────────────────────────────────────────── */
+
+/// Inline style of one run of an entry's text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct SpanStyle {
+    /// Replaces the entry's color; `None` keeps it.
+    pub color: Option<SpanColor>,
+    pub blink: bool,
+    /// Glyph cut out of a block of the span's color.
+    pub inverse: bool,
+    /// Drawn twice, one pixel apart.
+    pub bold: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpanColor {
+    Rgb(Rgb),
+    /// Per-letter rainbow, as [`RainbowOptions`] at speed 10.
+    Rainbow,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Span {
+    pub text: String,
+    pub style: SpanStyle,
+}
+
+/// Split `source` into styled spans.
+///
+/// `{tag}` starts a span and `{/}` ends the innermost one; spans nest,
+/// each inheriting from the one it sits in. A tag is a comma-separated
+/// list of any of: a color name (`red`, `green`, `blue`, `yellow`,
+/// `orange`, `cyan`, `magenta`, `purple`, `pink`, `white`, `gray`),
+/// `#rgb` / `#rrggbb`, `rainbow`, `blink`, `inverse` and `bold`.
+/// `{{` is a literal `{`; anything else in braces, and unmatched `{/}`,
+/// is left as text.
+#[must_use]
+pub fn parse_markup(source: &str) -> Vec<Span> {
+    let mut spans = Vec::new();
+    let mut stack = vec![SpanStyle::default()];
+    let mut text = String::new();
+    let mut rest = source;
+    while let Some(open) = rest.find('{') {
+        text.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        if let Some(tail) = after.strip_prefix('{') {
+            text.push('{');
+            rest = tail;
+            continue;
+        }
+        let current = *stack.last().expect("base style is never popped");
+        let tag = after
+            .split_once('}')
+            .and_then(|(tag, tail)| Some((parse_tag(current, tag, stack.len() > 1)?, tail)));
+        let Some((tag, tail)) = tag else {
+            text.push('{');
+            rest = after;
+            continue;
+        };
+        if !text.is_empty() {
+            spans.push(Span {
+                text: std::mem::take(&mut text),
+                style: current,
+            });
+        }
+        match tag {
+            Tag::Open(style) => stack.push(style),
+            Tag::Close => {
+                stack.pop();
+            }
+        }
+        rest = tail;
+    }
+    text.push_str(rest);
+    if !text.is_empty() {
+        spans.push(Span {
+            text,
+            style: *stack.last().expect("base style is never popped"),
+        });
+    }
+    spans
+}
+
+enum Tag {
+    Open(SpanStyle),
+    Close,
+}
+
+/// `tag` applied on top of `current`; `None` if it isn't a tag.
+fn parse_tag(current: SpanStyle, tag: &str, can_close: bool) -> Option<Tag> {
+    if tag == "/" {
+        return can_close.then_some(Tag::Close);
+    }
+    let mut style = current;
+    for part in tag.split(',').map(str::trim) {
+        match part {
+            "blink" => style.blink = true,
+            "inverse" => style.inverse = true,
+            "bold" => style.bold = true,
+            "rainbow" => style.color = Some(SpanColor::Rainbow),
+            color => style.color = Some(SpanColor::Rgb(parse_color(color)?)),
+        }
+    }
+    Some(Tag::Open(style))
+}
+
+fn parse_color(name: &str) -> Option<Rgb> {
+    let (r, g, b) = match name {
+        "red" => (255, 0, 0),
+        "green" => (0, 255, 0),
+        "blue" => (0, 0, 255),
+        "yellow" => (255, 255, 0),
+        "orange" => (255, 128, 0),
+        "cyan" => (0, 255, 255),
+        "magenta" => (255, 0, 255),
+        "purple" => (128, 0, 255),
+        "pink" => (255, 105, 180),
+        "white" => (255, 255, 255),
+        "gray" | "grey" => (128, 128, 128),
+        hex => {
+            let hex = hex.strip_prefix('#')?;
+            let digit = |i: usize| u8::from_str_radix(hex.get(i..=i)?, 16).ok();
+            match hex.len() {
+                3 => (digit(0)? * 17, digit(1)? * 17, digit(2)? * 17),
+                6 => {
+                    let byte = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+                    (byte(0)?, byte(2)?, byte(4)?)
+                }
+                _ => return None,
+            }
+        }
+    };
+    Some(Rgb { r, g, b })
+}
@@ -0,0 +1,410 @@
+//! Weather mode. Shows current conditions — an icon, the temperature
+//! and today's high / low — from a [`WeatherReport`] the driver's
+//! weather provider fetched. Icons are 16×16 sprites, scaled up on
+//! bigger panels. Caller supplies the report; renderer is pure.
+
+use embedded_graphics::{pixelcolor::Rgb888, prelude::*, Pixel};
+use serde::{Deserialize, Serialize};
+
+use crate::font::{Font, FontId};
+use crate::text::Rgb;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum WeatherCondition {
+    #[default]
+    Clear,
+    PartlyCloudy,
+    Cloudy,
+    Fog,
+    Drizzle,
+    Rain,
+    Snow,
+    Thunderstorm,
+}
+
+/// Current conditions as a provider reports them. Temperatures are
+/// in °C whatever the panel shows.
+#[derive(Clone, Copy, Debug, PartialEq, Default, Deserialize, Serialize)]
+pub struct WeatherReport {
+    #[serde(default)]
+    pub condition: WeatherCondition,
+    /// Night swaps the sun for the moon.
+    #[serde(default = "default_is_day")]
+    pub is_day: bool,
+    pub temperature: f32,
+    pub high: f32,
+    pub low: f32,
+}
+
+fn default_is_day() -> bool {
+    true
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum TemperatureUnit {
+    #[default]
+    Celsius,
+    Fahrenheit,
+}
+
+impl TemperatureUnit {
+    fn convert(self, celsius: f32) -> f32 {
+        match self {
+            Self::Celsius => celsius,
+            Self::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+        }
+    }
+}
+
+/// Persisted shape — what the dash writes into `panels.mode_config`
+/// for weather-mode panels. Where the weather comes from is the
+/// driver's `[weather]` config, not the panel's; the driver adds the
+/// latest report per render.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct WeatherSceneConfig {
+    #[serde(default)]
+    pub units: TemperatureUnit,
+    /// Color of the temperature; the icon keeps its own colors.
+    #[serde(default = "default_weather_color")]
+    pub color: Rgb,
+    /// Font for the temperature. High / low use the default font.
+    #[serde(default)]
+    pub font: FontId,
+    #[serde(default = "default_show_range")]
+    pub show_range: bool,
+}
+
+impl Default for WeatherSceneConfig {
+    fn default() -> Self {
+        Self {
+            units: TemperatureUnit::default(),
+            color: default_weather_color(),
+            font: FontId::default(),
+            show_range: default_show_range(),
+        }
+    }
+}
+
+impl WeatherSceneConfig {
+    /// Combine the persisted config with the latest report into a
+    /// render-ready `WeatherScene`. `None` (nothing fetched yet) draws
+    /// a placeholder temperature and no icon.
+    #[must_use]
+    pub fn into_frame(self, report: Option<WeatherReport>) -> WeatherScene {
+        WeatherScene {
+            report,
+            units: self.units,
+            color: self.color,
+            font: self.font,
+            show_range: self.show_range,
+        }
+    }
+}
+
+fn default_weather_color() -> Rgb {
+    Rgb {
+        r: 0xff,
+        g: 0xff,
+        b: 0xff,
+    }
+}
+
+fn default_show_range() -> bool {
+    true
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct WeatherScene {
+    #[serde(default)]
+    pub report: Option<WeatherReport>,
+    #[serde(default)]
+    pub units: TemperatureUnit,
+    #[serde(default = "default_weather_color")]
+    pub color: Rgb,
+    #[serde(default)]
+    pub font: FontId,
+    #[serde(default = "default_show_range")]
+    pub show_range: bool,
+}
+
+impl Default for WeatherScene {
+    fn default() -> Self {
+        WeatherSceneConfig::default().into_frame(None)
+    }
+}
+
+/// Pixels between the icon, the temperature and the range.
+const GAP: i32 = 1;
+/// Color of the high / low line.
+const RANGE_COLOR: Rgb888 = Rgb888::new(0x90, 0x90, 0x90);
+
+#[allow(clippy::cast_possible_wrap)]
+#[allow(clippy::cast_possible_truncation)]
+pub fn render<D>(frame: &WeatherScene, _step: usize, canvas: &mut D) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = Rgb888> + OriginDimensions,
+{
+    let size = canvas.size();
+    let (canvas_w, canvas_h) = (size.width as i32, size.height as i32);
+    let font = Font::resolve(&frame.font);
+    let range_font = Font::resolve(&FontId::default());
+    let degrees = |celsius: f32| frame.units.convert(celsius).round() as i32;
+
+    let temperature = frame.report.map_or_else(
+        || "--°".to_owned(),
+        |r| format!("{}°", degrees(r.temperature)),
+    );
+    let range = frame
+        .report
+        .filter(|_| frame.show_range)
+        .map(|r| format!("H{} L{}", degrees(r.high), degrees(r.low)));
+
+    let scale = (canvas_w.min(canvas_h) / 32).max(1);
+    let icon = SPRITE_SIZE * scale;
+    let range_h = range.as_ref().map_or(0, |_| GAP + range_font.line_height());
+    let mut y = (canvas_h - icon - GAP - font.line_height() - range_h) / 2;
+
+    if let Some(report) = frame.report {
+        let origin = Point::new((canvas_w - icon) / 2, y);
+        for &(sprite, dx, dy) in layers(report.condition, report.is_day) {
+            draw_sprite(sprite, Point::new(dx, dy), origin, scale, canvas)?;
+        }
+    }
+    y += icon + GAP;
+
+    let centered =
+        |text: &str, font: &Font, y| Point::new((canvas_w - font.text_width(text)) / 2, y);
+    let color = frame.color.into();
+    font.draw(
+        &temperature,
+        centered(&temperature, &font, y),
+        color,
+        canvas,
+    )?;
+    y += font.line_height() + GAP;
+    if let Some(range) = range {
+        let top_left = centered(&range, &range_font, y);
+        range_font.draw(&range, top_left, RANGE_COLOR, canvas)?;
+    }
+    Ok(())
+}
+
+/* ─── icons ─────────────────────────────────────────────────────── */
+
+const SPRITE_SIZE: i32 = 16;
+
+/// A 16×16 icon layer, one string per row. Spaces are transparent;
+/// letters pick a color from [`palette`].
+type Sprite = [&'static str; 16];
+
+/// A sprite shifted by `(dx, dy)` sprite pixels. Whatever lands
+/// outside the 16×16 cell is cut off.
+type Layer = (&'static Sprite, i32, i32);
+
+/// The sprites making up each condition's icon, back to front.
+fn layers(condition: WeatherCondition, is_day: bool) -> &'static [Layer] {
+    match (condition, is_day) {
+        (WeatherCondition::Clear, true) => &[(&SUN, 0, 0)],
+        (WeatherCondition::Clear, false) => &[(&MOON, 0, 0)],
+        (WeatherCondition::PartlyCloudy, true) => &[(&SUN, 3, -3), (&CLOUD, 0, 3)],
+        (WeatherCondition::PartlyCloudy, false) => &[(&MOON, 3, -1), (&CLOUD, 0, 3)],
+        (WeatherCondition::Cloudy, _) => &[(&CLOUD, 0, 2)],
+        (WeatherCondition::Fog, _) => &[(&FOG, 0, 0)],
+        (WeatherCondition::Drizzle, _) => &[(&CLOUD, 0, -2), (&DRIZZLE, 0, 0)],
+        (WeatherCondition::Rain, _) => &[(&CLOUD, 0, -2), (&RAIN, 0, 0)],
+        (WeatherCondition::Snow, _) => &[(&CLOUD, 0, -2), (&SNOW, 0, 0)],
+        (WeatherCondition::Thunderstorm, _) => &[(&CLOUD, 0, -2), (&BOLT, 0, 0)],
+    }
+}
+
+fn palette(key: char) -> Rgb888 {
+    match key {
+        'Y' => Rgb888::new(0xff, 0xc8, 0x00),
+        'M' => Rgb888::new(0xf0, 0xe6, 0x8c),
+        'W' => Rgb888::new(0xe6, 0xe6, 0xe6),
+        'G' => Rgb888::new(0x80, 0x80, 0x80),
+        'B' => Rgb888::new(0x3c, 0x8c, 0xff),
+        _ => Rgb888::WHITE,
+    }
+}
+
+/// Draw `sprite` shifted by `offset` into the cell at `origin`, each
+/// sprite pixel a `scale`×`scale` block.
+#[allow(clippy::cast_possible_wrap)]
+fn draw_sprite<D>(
+    sprite: &Sprite,
+    offset: Point,
+    origin: Point,
+    scale: i32,
+    canvas: &mut D,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = Rgb888>,
+{
+    let cell = 0..SPRITE_SIZE;
+    let pixels = sprite.iter().enumerate().flat_map(|(row, line)| {
+        line.chars().enumerate().filter_map(move |(col, key)| {
+            let at = Point::new(col as i32, row as i32) + offset;
+            (key != ' ').then_some((at, palette(key)))
+        })
+    });
+    let blocks = pixels
+        .filter(|(at, _)| cell.contains(&at.x) && cell.contains(&at.y))
+        .flat_map(|(at, color)| {
+            let corner = origin + at * scale;
+            (0..scale * scale).map(move |i| Pixel(corner + Point::new(i % scale, i / scale), color))
+        });
+    canvas.draw_iter(blocks)
+}
+
+const SUN: Sprite = [
+    "       YY       ",
+    "  Y    YY    Y  ",
+    "   Y        Y   ",
+    "      YYYY      ",
+    "    YYYYYYYY    ",
+    "    YYYYYYYY    ",
+    "   YYYYYYYYYY   ",
+    "YY YYYYYYYYYY YY",
+    "YY YYYYYYYYYY YY",
+    "   YYYYYYYYYY   ",
+    "    YYYYYYYY    ",
+    "    YYYYYYYY    ",
+    "      YYYY      ",
+    "   Y        Y   ",
+    "  Y    YY    Y  ",
+    "       YY       ",
+];
+
+const MOON: Sprite = [
+    "                ",
+    "     MMMM       ",
+    "   MMMMM        ",
+    "  MMMMM         ",
+    "  MMMM          ",
+    " MMMMM          ",
+    " MMMM           ",
+    " MMMM           ",
+    " MMMM           ",
+    " MMMMM          ",
+    "  MMMMM       M ",
+    "  MMMMMM    MM  ",
+    "   MMMMMMMMMMM  ",
+    "     MMMMMMM    ",
+    "                ",
+    "                ",
+];
+
+const CLOUD: Sprite = [
+    "                ",
+    "                ",
+    "                ",
+    "      WWWW      ",
+    "     WWWWWW     ",
+    "  WWWWWWWWWWW   ",
+    " WWWWWWWWWWWWW  ",
+    "WWWWWWWWWWWWWWW ",
+    "WWWWWWWWWWWWWWWW",
+    "WWWWWWWWWWWWWWWW",
+    " GGGGGGGGGGGGGG ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+];
+
+const RAIN: Sprite = [
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "  B   B   B   B ",
+    " B   B   B   B  ",
+    "                ",
+    "   B   B   B    ",
+    "  B   B   B     ",
+    "                ",
+];
+
+const DRIZZLE: Sprite = [
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "   B     B      ",
+    "                ",
+    "      B     B   ",
+    "                ",
+    "   B     B      ",
+    "                ",
+];
+
+const SNOW: Sprite = [
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "  S    S    S   ",
+    " SSS  SSS  SSS  ",
+    "  S    S    S   ",
+    "                ",
+    "     S    S     ",
+    "    SSS  SSS    ",
+];
+
+const BOLT: Sprite = [
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+    "       YYY      ",
+    "      YYY       ",
+    "     YYYYY      ",
+    "       YY       ",
+    "      YY        ",
+    "     Y          ",
+    "                ",
+];
+
+const FOG: Sprite = [
+    "                ",
+    "                ",
+    "                ",
+    " GGGGGGGGGGGG   ",
+    "                ",
+    "   GGGGGGGGGGGG ",
+    "                ",
+    " GGGGGGGGGGGG   ",
+    "                ",
+    "   GGGGGGGGGGGG ",
+    "                ",
+    " GGGGGGGGGGGG   ",
+    "                ",
+    "                ",
+    "                ",
+    "                ",
+];
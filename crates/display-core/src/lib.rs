@@ -7,7 +7,8 @@
 //! Architecture: each render mode (text, clock, image, …) lives in
 //! its own module and exposes its own per-mode frame type. The
 //! top-level [`Scene`] tags which mode to dispatch to and carries
-//! mode-independent panel state (flash, pause).
+//! mode-independent panel state (flash, pause). [`transition`]
+//! composites two scenes when the driver switches between them.
 
 use std::sync::Arc;
 
@@ -19,9 +20,13 @@ use embedded_graphics::{
 };
 use serde::{Deserialize, Serialize};
 
+pub mod calibration;
+pub mod color;
+pub mod font;
 pub mod frames;
+pub mod transition;
 
-pub use frames::{boot, clock, gif, image, life, setup, shapes, test, text};
+pub use frames::{boot, chart, clock, countdown, gauge, gif, image, life, playlist, setup, shapes, test, text, weather};
 pub use frames::text::{
     MarqueeOptions, RainbowOptions, Rgb, TextEntry, TextEntryColor, TextEntryOptions,
 };
@@ -38,7 +43,11 @@ pub struct FlashState {
 /// renderer runs. `is_off` short-circuits the dispatch entirely
 /// (panel renders fully black) without disturbing the configured
 /// mode — flipping back gives you the same scene you left.
-/// `brightness` is a final 0.0–1.0 multiplier applied to every pixel.
+/// `brightness` is a final 0.0–1.0 multiplier applied to every pixel,
+/// shaped by `brightness_curve` and optionally dithered.
+/// `rotation` and the flips reorient the whole frame for panels
+/// mounted upside-down or in portrait; renderers see the rotated
+/// size, so text lays out along the panel as mounted.
 // Not `Eq`: `brightness` is an f32.
 #[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
 pub struct PanelState {
@@ -50,12 +59,46 @@ pub struct PanelState {
     /// so it defaults to full rather than black.
     #[serde(default = "full_brightness")]
     pub brightness: f32,
+    /// How `brightness` maps onto pixel values. Linear for older
+    /// persisted scenes.
+    #[serde(default)]
+    pub brightness_curve: BrightnessCurve,
+    /// Dither dimmed pixels between the two nearest levels, in a
+    /// pattern that shifts every step, so a dim scene keeps its
+    /// gradients instead of banding.
+    #[serde(default)]
+    pub dither: bool,
+    /// Clockwise rotation in degrees: 0, 90, 180 or 270. Anything
+    /// else rounds down to a quarter turn.
+    #[serde(default)]
+    pub rotation: u16,
+    /// Mirror left-right, after rotating.
+    #[serde(default)]
+    pub flip_horizontal: bool,
+    /// Mirror top-bottom, after rotating.
+    #[serde(default)]
+    pub flip_vertical: bool,
 }
 
 fn full_brightness() -> f32 {
     1.0
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum BrightnessCurve {
+    /// Every channel value times `brightness`.
+    #[default]
+    Linear,
+    /// `brightness` is the fraction of light the panel gives off.
+    /// Channel values are gamma-encoded, so they scale by
+    /// `brightness^(1/2.2)`: 10% keeps dim colors visible instead of
+    /// snapping them to black.
+    Perceptual,
+}
+
+/// Display gamma the perceptual curve assumes.
+const GAMMA: f32 = 2.2;
+
 impl Default for PanelState {
     fn default() -> Self {
         Self {
@@ -63,8 +106,44 @@ impl Default for PanelState {
             is_off: false,
             flash: FlashState::default(),
             brightness: 1.0,
+            brightness_curve: BrightnessCurve::default(),
+            dither: false,
+            rotation: 0,
+            flip_horizontal: false,
+            flip_vertical: false,
+        }
+    }
+}
+
+impl PanelState {
+    /// Size renderers lay out on for a `physical` canvas: width and
+    /// height swap on a quarter turn.
+    #[must_use]
+    pub fn logical_size(&self, physical: Size) -> Size {
+        if self.quarter_turns() % 2 == 1 {
+            Size::new(physical.height, physical.width)
+        } else {
+            physical
+        }
+    }
+
+    /// Multiplier for channel values: `brightness` through the curve.
+    #[must_use]
+    pub fn brightness_scale(&self) -> f32 {
+        let brightness = self.brightness.clamp(0.0, 1.0);
+        match self.brightness_curve {
+            BrightnessCurve::Linear => brightness,
+            BrightnessCurve::Perceptual => brightness.powf(GAMMA.recip()),
         }
     }
+
+    fn quarter_turns(&self) -> u16 {
+        self.rotation / 90 % 4
+    }
+
+    fn is_reoriented(&self) -> bool {
+        self.quarter_turns() != 0 || self.flip_horizontal || self.flip_vertical
+    }
 }
 
 /// Tagged union over render modes. Externally-tagged so JSON looks
@@ -79,6 +158,10 @@ impl Default for PanelState {
 pub enum Mode {
     Text(text::TextScene),
     Clock(clock::ClockScene),
+    Countdown(countdown::CountdownScene),
+    Chart(chart::ChartScene),
+    Gauge(gauge::GaugeScene),
+    Weather(weather::WeatherScene),
     Life(life::LifeScene),
     Image(Arc<image::ImageScene>),
     Gif(Arc<gif::GifScene>),
@@ -86,6 +169,7 @@ pub enum Mode {
     Test(test::TestScene),
     Boot(boot::BootScene),
     Setup(setup::SetupScene),
+    Playlist(playlist::PlaylistScene),
 }
 
 impl Default for Mode {
@@ -118,7 +202,25 @@ where
         return Ok(());
     }
 
-    let brightness = frame.panel.brightness.clamp(0.0, 1.0);
+    if frame.panel.is_reoriented() {
+        // Same trick as brightness below: every drawn pixel passes
+        // through the wrapper, which reports the rotated size and
+        // moves each pixel to where it lands on the mounted panel.
+        let mut oriented = OrientedTarget {
+            inner: canvas,
+            panel: &frame.panel,
+        };
+        dim_and_dispatch(frame, step, &mut oriented)
+    } else {
+        dim_and_dispatch(frame, step, canvas)
+    }
+}
+
+fn dim_and_dispatch<D>(frame: &Scene, step: usize, canvas: &mut D) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = Rgb888> + OriginDimensions,
+{
+    let brightness = frame.panel.brightness_scale();
     if brightness >= 0.999 {
         dispatch(frame, step, canvas)
     } else {
@@ -129,6 +231,7 @@ where
         let mut dimmed = BrightnessTarget {
             inner: canvas,
             scale: brightness,
+            dither: frame.panel.dither.then_some(step),
         };
         dispatch(frame, step, &mut dimmed)
     }
@@ -139,28 +242,54 @@ where
     D: DrawTarget<Color = Rgb888> + OriginDimensions,
 {
     match &frame.mode {
-        Mode::Text(t) => text::render(t, step, canvas)?,
-        Mode::Clock(c) => clock::render(c, canvas)?,
-        Mode::Life(l) => life::render(l, canvas)?,
-        Mode::Image(i) => image::render(i.as_ref(), canvas)?,
-        Mode::Gif(g) => gif::render(g.as_ref(), step, canvas)?,
-        Mode::Shapes(s) => shapes::render(s, step, canvas)?,
-        Mode::Test(t) => test::render(t, canvas)?,
-        Mode::Boot(b) => boot::render(b, step, canvas)?,
-        Mode::Setup(s) => setup::render(s, step, canvas)?,
+        Mode::Playlist(p) => playlist::render(p, step, canvas)?,
+        mode => render_mode(mode, step, canvas)?,
     }
     apply_flash(canvas, &frame.panel, step)?;
     Ok(())
 }
 
+/// Run the per-mode renderer for `mode`. Playlists call back into
+/// this for their children; a nested playlist renders nothing, which
+/// also keeps the generic recursion finite.
+pub(crate) fn render_mode<D>(mode: &Mode, step: usize, canvas: &mut D) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = Rgb888> + OriginDimensions,
+{
+    match mode {
+        Mode::Text(t) => text::render(t, step, canvas),
+        Mode::Clock(c) => clock::render(c, step, canvas),
+        Mode::Countdown(c) => countdown::render(c, step, canvas),
+        Mode::Chart(c) => chart::render(c, step, canvas),
+        Mode::Gauge(g) => gauge::render(g, step, canvas),
+        Mode::Weather(w) => weather::render(w, step, canvas),
+        Mode::Life(l) => life::render(l, step, canvas),
+        Mode::Image(i) => image::render(i.as_ref(), canvas),
+        Mode::Gif(g) => gif::render(g.as_ref(), step, canvas),
+        Mode::Shapes(s) => shapes::render(s, step, canvas),
+        Mode::Test(t) => test::render(t, canvas),
+        Mode::Boot(b) => boot::render(b, step, canvas),
+        Mode::Setup(s) => setup::render(s, step, canvas),
+        Mode::Playlist(_) => Ok(()),
+    }
+}
+
 /// `DrawTarget` wrapper that scales every pixel's color by `scale`
 /// before forwarding to the real canvas. Used to apply the panel's
-/// global brightness as a final multiply.
-struct BrightnessTarget<'a, D> {
-    inner: &'a mut D,
-    scale: f32,
+/// global brightness as a final multiply, and by playlist fades.
+pub(crate) struct BrightnessTarget<'a, D> {
+    pub(crate) inner: &'a mut D,
+    pub(crate) scale: f32,
+    /// `Some(step)` dithers each scaled channel between the levels
+    /// either side of it, in an ordered pattern shifted by `step`.
+    /// `None` truncates to the level below.
+    pub(crate) dither: Option<usize>,
 }
 
+/// 4×4 Bayer matrix: a threshold order that spreads any fraction of
+/// lit cells evenly across the tile.
+const BAYER: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
 #[allow(clippy::cast_possible_truncation)]
 #[allow(clippy::cast_sign_loss)]
 impl<D> DrawTarget for BrightnessTarget<'_, D>
@@ -175,12 +304,24 @@ where
         I: IntoIterator<Item = Pixel<Rgb888>>,
     {
         let s = self.scale;
-        let scale = |c: u8| (f32::from(c) * s) as u8;
-        self.inner.draw_iter(
-            pixels
-                .into_iter()
-                .map(|Pixel(p, c)| Pixel(p, Rgb888::new(scale(c.r()), scale(c.g()), scale(c.b())))),
-        )
+        let dither = self.dither;
+        self.inner.draw_iter(pixels.into_iter().map(|Pixel(p, c)| {
+            // Threshold in (0, 1) for this pixel. Stepping through all
+            // 16 offsets in turn, every pixel sees every threshold, so
+            // over time it averages out to the exact scaled value. A
+            // threshold of 1 never rounds up: plain truncation.
+            let threshold = dither.map_or(1.0, |step| {
+                let cell = BAYER[(p.y & 3) as usize][(p.x & 3) as usize];
+                let rank = (usize::from(cell) + step.wrapping_mul(7)) % 16;
+                (rank as f32 + 0.5) / 16.0
+            });
+            let scale = |c: u8| {
+                let v = f32::from(c) * s;
+                let level = v.floor();
+                (if v - level >= threshold { level + 1.0 } else { level }) as u8
+            };
+            Pixel(p, Rgb888::new(scale(c.r()), scale(c.g()), scale(c.b())))
+        }))
     }
 }
 
@@ -193,15 +334,73 @@ where
     }
 }
 
+/// `DrawTarget` wrapper that rotates and mirrors the frame onto the
+/// real canvas. Reports the logical (rotated) size, so renderers lay
+/// out for the panel as mounted.
+struct OrientedTarget<'a, D> {
+    inner: &'a mut D,
+    panel: &'a PanelState,
+}
+
+#[allow(clippy::cast_possible_wrap)]
+impl<D> DrawTarget for OrientedTarget<'_, D>
+where
+    D: DrawTarget<Color = Rgb888> + OriginDimensions,
+{
+    type Color = Rgb888;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Rgb888>>,
+    {
+        let size = self.inner.size();
+        let (w, h) = (size.width as i32, size.height as i32);
+        let panel = self.panel;
+        self.inner.draw_iter(pixels.into_iter().map(|Pixel(p, c)| {
+            let (x, y) = match panel.quarter_turns() {
+                1 => (w - 1 - p.y, p.x),
+                2 => (w - 1 - p.x, h - 1 - p.y),
+                3 => (p.y, h - 1 - p.x),
+                _ => (p.x, p.y),
+            };
+            let x = if panel.flip_horizontal { w - 1 - x } else { x };
+            let y = if panel.flip_vertical { h - 1 - y } else { y };
+            Pixel(Point::new(x, y), c)
+        }))
+    }
+}
+
+impl<D> OriginDimensions for OrientedTarget<'_, D>
+where
+    D: OriginDimensions,
+{
+    fn size(&self) -> Size {
+        self.panel.logical_size(self.inner.size())
+    }
+}
+
 fn apply_flash<D>(canvas: &mut D, panel: &PanelState, step: usize) -> Result<(), D::Error>
 where
     D: DrawTarget<Color = Rgb888> + OriginDimensions,
 {
-    if !panel.flash.is_active || panel.is_paused || panel.flash.total_steps == 0 {
+    if !panel.flash.is_active || panel.is_paused {
+        return Ok(());
+    }
+    draw_flash(canvas, &panel.flash, step)
+}
+
+/// Fill the canvas white during the on part of `flash`'s cycle.
+/// Countdowns call this directly for their finish flash.
+pub(crate) fn draw_flash<D>(canvas: &mut D, flash: &FlashState, step: usize) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = Rgb888> + OriginDimensions,
+{
+    if flash.total_steps == 0 {
         return Ok(());
     }
-    let progress = step % panel.flash.total_steps;
-    if progress >= panel.flash.on_steps {
+    let progress = step % flash.total_steps;
+    if progress >= flash.on_steps {
         return Ok(());
     }
     let style = PrimitiveStyleBuilder::new()
@@ -0,0 +1,209 @@
+//! Transitions between two [`Scene`]s.
+//!
+//! Both scenes render in full (flash, brightness, `is_off` and all)
+//! into off-screen buffers the size of the canvas, and the pair is
+//! composited per pixel according to [`TransitionKind`]. The driver
+//! runs one whenever the panel's mode or content changes, so a panel
+//! set to anything but [`TransitionKind::Cut`] blends edits in.
+
+use embedded_graphics::{pixelcolor::Rgb888, prelude::*, Pixel};
+use serde::{Deserialize, Serialize};
+
+use crate::Scene;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum TransitionKind {
+    /// No transition; the new scene replaces the old one outright.
+    #[default]
+    Cut,
+    /// Linear blend from old to new.
+    Crossfade,
+    /// New scene revealed left to right.
+    WipeHorizontal,
+    /// New scene revealed top to bottom.
+    WipeVertical,
+    /// New scene pushes the old one out to the left.
+    SlideHorizontal,
+    /// New scene pushes the old one out to the top.
+    SlideVertical,
+    /// New scene appears pixel by pixel in a fixed scattered order.
+    Dissolve,
+}
+
+/// Persisted shape — `panels.transition`. Empty is a cut, so panels
+/// only blend once someone picks a kind.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Transition {
+    #[serde(default)]
+    pub kind: TransitionKind,
+    /// Length of the transition in frames, once a kind is picked.
+    #[serde(default = "default_transition_steps")]
+    pub steps: usize,
+}
+
+fn default_transition_steps() -> usize {
+    30
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Self {
+            kind: TransitionKind::default(),
+            steps: default_transition_steps(),
+        }
+    }
+}
+
+impl Transition {
+    /// Whether a transition `elapsed` frames in has finished, i.e.
+    /// [`render`] would just draw the new scene.
+    #[must_use]
+    pub fn is_done(&self, elapsed: usize) -> bool {
+        self.kind == TransitionKind::Cut || elapsed >= self.steps
+    }
+}
+
+/// Render `elapsed` frames into a transition from `from` (drawn at
+/// `from_step`) to `to` (drawn at `to_step`). Once the transition is
+/// done this is exactly [`crate::render`] of `to`.
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_possible_wrap)]
+#[allow(clippy::cast_precision_loss)]
+pub fn render<D>(
+    transition: &Transition,
+    elapsed: usize,
+    from: &Scene,
+    from_step: usize,
+    to: &Scene,
+    to_step: usize,
+    canvas: &mut D,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = Rgb888> + OriginDimensions,
+{
+    if transition.is_done(elapsed) {
+        return crate::render(to, to_step, canvas);
+    }
+    let size = canvas.size();
+    let mut old = Offscreen::new(size);
+    let mut new = Offscreen::new(size);
+    crate::render(from, from_step, &mut old).unwrap_or_else(|never| match never {});
+    crate::render(to, to_step, &mut new).unwrap_or_else(|never| match never {});
+
+    let t = elapsed as f32 / transition.steps as f32;
+    let (w, h) = (size.width as i32, size.height as i32);
+    let kind = transition.kind;
+    let pixels = (0..h).flat_map(|y| (0..w).map(move |x| (x, y))).map(|(x, y)| {
+        let color = match kind {
+            TransitionKind::Cut => new.at(x, y),
+            TransitionKind::Crossfade => blend(old.at(x, y), new.at(x, y), t),
+            TransitionKind::WipeHorizontal => {
+                if x < (w as f32 * t) as i32 {
+                    new.at(x, y)
+                } else {
+                    old.at(x, y)
+                }
+            }
+            TransitionKind::WipeVertical => {
+                if y < (h as f32 * t) as i32 {
+                    new.at(x, y)
+                } else {
+                    old.at(x, y)
+                }
+            }
+            TransitionKind::SlideHorizontal => {
+                let shift = (w as f32 * t) as i32;
+                if x < w - shift {
+                    old.at(x + shift, y)
+                } else {
+                    new.at(x - (w - shift), y)
+                }
+            }
+            TransitionKind::SlideVertical => {
+                let shift = (h as f32 * t) as i32;
+                if y < h - shift {
+                    old.at(x, y + shift)
+                } else {
+                    new.at(x, y - (h - shift))
+                }
+            }
+            TransitionKind::Dissolve => {
+                if f32::from(scatter(x, y)) < t * 256.0 {
+                    new.at(x, y)
+                } else {
+                    old.at(x, y)
+                }
+            }
+        };
+        Pixel(Point::new(x, y), color)
+    });
+    canvas.draw_iter(pixels)
+}
+
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_sign_loss)]
+fn blend(a: Rgb888, b: Rgb888, t: f32) -> Rgb888 {
+    let mix = |a: u8, b: u8| (f32::from(a) + (f32::from(b) - f32::from(a)) * t) as u8;
+    Rgb888::new(mix(a.r(), b.r()), mix(a.g(), b.g()), mix(a.b(), b.b()))
+}
+
+/// Stable pseudo-random 0..=255 per pixel — the order `Dissolve`
+/// flips pixels in.
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_sign_loss)]
+fn scatter(x: i32, y: i32) -> u8 {
+    let mut v = (x as u32).wrapping_mul(374_761_393) ^ (y as u32).wrapping_mul(668_265_263);
+    v = (v ^ (v >> 13)).wrapping_mul(1_274_126_177);
+    (v >> 24) as u8
+}
+
+/// Owned RGB buffer one scene renders into before compositing.
+struct Offscreen {
+    width: i32,
+    height: i32,
+    pixels: Vec<Rgb888>,
+}
+
+#[allow(clippy::cast_possible_wrap)]
+impl Offscreen {
+    fn new(size: Size) -> Self {
+        Self {
+            width: size.width as i32,
+            height: size.height as i32,
+            pixels: vec![Rgb888::BLACK; (size.width * size.height) as usize],
+        }
+    }
+
+    #[allow(clippy::cast_sign_loss)]
+    fn at(&self, x: i32, y: i32) -> Rgb888 {
+        if x < 0 || y < 0 || x >= self.width || y >= self.height {
+            return Rgb888::BLACK;
+        }
+        self.pixels[(y * self.width + x) as usize]
+    }
+}
+
+impl DrawTarget for Offscreen {
+    type Color = Rgb888;
+    type Error = core::convert::Infallible;
+
+    #[allow(clippy::cast_sign_loss)]
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Rgb888>>,
+    {
+        for Pixel(p, color) in pixels {
+            if p.x >= 0 && p.y >= 0 && p.x < self.width && p.y < self.height {
+                self.pixels[(p.y * self.width + p.x) as usize] = color;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[allow(clippy::cast_sign_loss)]
+impl OriginDimensions for Offscreen {
+    fn size(&self) -> Size {
+        Size::new(self.width as u32, self.height as u32)
+    }
+}
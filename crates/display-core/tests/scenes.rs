@@ -7,15 +7,30 @@
 use std::sync::Arc;
 
 use display_core::{
-    clock::{ClockFormat, ClockScene, ClockTime},
+    calibration::{Calibration, Channels},
+    chart::{ChartScene, ChartStyle, ChartThreshold},
+    clock::{
+        self, ClockFormat, ClockScene, ClockSceneConfig, ClockStyle, ClockTime, ClockZone, DateFormat,
+        WeekdayFormat,
+    },
+    color::{GradientDirection, GradientOptions, PaletteOptions, PulseOptions},
+    countdown::{CountdownFinish, CountdownPrecision, CountdownScene, CountdownSceneConfig},
+    font::{self, BdfFont, FontId},
+    gauge::{GaugeScene, GaugeStyle},
     gif::{GifFrame, GifScene},
     image::ImageScene,
     life::LifeScene,
+    playlist::{PlaylistItem, PlaylistScene, PlaylistTransition},
     render,
     shapes::{ShapeKind, ShapesScene},
     test::{TestPattern, TestScene},
-    text::{Rgb, TextEntry, TextEntryColor, TextEntryOptions},
-    Mode, PanelState, Scene,
+    text::{
+        AutoScroll, HorizontalAlign, MarqueeDirection, SpanStyle, Rgb, TextEffect, TextEntry, TextEntryColor,
+        TextEntryOptions, TextLayout, VerticalAlign,
+    },
+    transition::{self, Transition, TransitionKind},
+    weather::{TemperatureUnit, WeatherCondition, WeatherReport, WeatherScene},
+    BrightnessCurve, FlashState, Mode, PanelState, Scene,
 };
 use embedded_graphics::{
     pixelcolor::Rgb888,
@@ -138,11 +153,20 @@ fn is_off_short_circuits_render() {
         text: "hello".into(),
         options: TextEntryOptions {
             color: TextEntryColor::Rainbow(RainbowOptions { is_per_letter: false, speed: 1 }),
-            marquee: MarqueeOptions { speed: 0 },
+            marquee: MarqueeOptions { speed: 0, direction: MarqueeDirection::RightToLeft },
+            font: FontId::default(),
+            align: HorizontalAlign::Left,
+            wrap: false,
+            markup: false,
+            effect: TextEffect::None,
         },
     }];
     let mut canvas = MockCanvas::new(W, H);
-    let mut scene = scene_with(Mode::Text(display_core::text::TextScene { entries, scroll: 0 }));
+    let mut scene = scene_with(Mode::Text(display_core::text::TextScene {
+        entries,
+        scroll: 0,
+        layout: TextLayout::default(),
+    }));
     scene.panel.is_off = true;
     scene.panel.flash.is_active = true;
     scene.panel.flash.on_steps = 4;
@@ -152,58 +176,1436 @@ fn is_off_short_circuits_render() {
 }
 
 #[test]
-fn pause_disables_flash_overlay() {
-    let mut canvas = MockCanvas::new(W, H);
-    let mut scene = scene_with(Mode::default());
-    scene.panel.is_paused = true;
-    scene.panel.flash.is_active = true;
-    scene.panel.flash.on_steps = 4;
-    scene.panel.flash.total_steps = 8;
-    render(&scene, 0, &mut canvas).unwrap();
-    assert_eq!(
-        canvas.lit_count(),
+fn pause_disables_flash_overlay() {
+    let mut canvas = MockCanvas::new(W, H);
+    let mut scene = scene_with(Mode::default());
+    scene.panel.is_paused = true;
+    scene.panel.flash.is_active = true;
+    scene.panel.flash.on_steps = 4;
+    scene.panel.flash.total_steps = 8;
+    render(&scene, 0, &mut canvas).unwrap();
+    assert_eq!(
+        canvas.lit_count(),
+        0,
+        "paused panel should suppress the flash overlay"
+    );
+}
+
+/* ─── text ───────────────────────────────────────────────────────── */
+
+#[test]
+fn text_entry_renders_some_pixels() {
+    let entries = vec![TextEntry {
+        text: "hi".into(),
+        options: TextEntryOptions {
+            color: TextEntryColor::Rgb(Rgb { r: 255, g: 138, b: 44 }),
+            marquee: display_core::MarqueeOptions {
+                speed: 0,
+                direction: MarqueeDirection::RightToLeft,
+            },
+            font: FontId::default(),
+            align: HorizontalAlign::Left,
+            wrap: false,
+            markup: false,
+            effect: TextEffect::None,
+        },
+    }];
+    let scene = scene_with(Mode::Text(display_core::text::TextScene {
+        entries,
+        scroll: 0,
+        layout: TextLayout::default(),
+    }));
+    let mut canvas = MockCanvas::new(W, H);
+    render(&scene, 0, &mut canvas).unwrap();
+    assert!(
+        canvas.lit_count() > 0,
+        "rendered text should light up at least some pixels"
+    );
+}
+
+/* ─── fonts ──────────────────────────────────────────────────────── */
+
+fn text_scene(lines: &[(&str, FontId)]) -> Scene {
+    let entries = lines.iter().map(|(text, font)| white_entry(text, font.clone())).collect();
+    scene_with(Mode::Text(display_core::text::TextScene {
+        entries,
+        scroll: 0,
+        layout: TextLayout::default(),
+    }))
+}
+
+fn white_entry(text: &str, font: FontId) -> TextEntry {
+    TextEntry {
+        text: text.to_owned(),
+        options: TextEntryOptions {
+            color: TextEntryColor::Rgb(Rgb { r: 255, g: 255, b: 255 }),
+            marquee: display_core::MarqueeOptions {
+                speed: 0,
+                direction: MarqueeDirection::RightToLeft,
+            },
+            font,
+            align: HorizontalAlign::Left,
+            wrap: false,
+            markup: false,
+            effect: TextEffect::None,
+        },
+    }
+}
+
+/// Rows `[first, last]` containing any lit pixel.
+fn lit_rows(canvas: &MockCanvas) -> Option<(u32, u32)> {
+    let rows: Vec<u32> = (0..H)
+        .filter(|&y| (0..W).any(|x| canvas.at(x, y) != Rgb888::BLACK))
+        .collect();
+    Some((*rows.first()?, *rows.last()?))
+}
+
+#[test]
+fn larger_font_renders_taller_text() {
+    let height = |font: FontId| {
+        let mut canvas = MockCanvas::new(W, H);
+        render(&text_scene(&[("HI", font)]), 0, &mut canvas).unwrap();
+        let (top, bottom) = lit_rows(&canvas).expect("text lights something");
+        bottom - top + 1
+    };
+    let small = height(FontId::Font4x6);
+    let default = height(FontId::Font5x8);
+    let large = height(FontId::Font10x20);
+    assert!(small < default && default < large, "{small} / {default} / {large}");
+}
+
+#[test]
+fn lines_stack_by_their_own_font_height() {
+    // A 10×20 headline pushes the next line below row 20.
+    let mut canvas = MockCanvas::new(W, H);
+    render(
+        &text_scene(&[("", FontId::Font10x20), ("X", FontId::Font5x8)]),
+        0,
+        &mut canvas,
+    )
+    .unwrap();
+    let (top, _) = lit_rows(&canvas).unwrap();
+    assert!(top >= 21, "second line starts at row {top}");
+}
+
+const TEST_BDF: &str = "\
+STARTFONT 2.1
+FONT -test-block-medium-r-normal--4-40-75-75-c-40-iso10646-1
+SIZE 4 75 75
+FONTBOUNDINGBOX 4 4 0 0
+STARTPROPERTIES 2
+FONT_ASCENT 4
+FONT_DESCENT 0
+ENDPROPERTIES
+CHARS 1
+STARTCHAR block
+ENCODING 9608
+SWIDTH 1000 0
+DWIDTH 5 0
+BBX 4 4 0 0
+BITMAP
+F0
+F0
+F0
+F0
+ENDCHAR
+ENDFONT
+";
+
+#[test]
+fn bdf_font_renders_registered_glyphs() {
+    font::register_bdf("test-block", BdfFont::parse(TEST_BDF).unwrap());
+    let mut canvas = MockCanvas::new(W, H);
+    render(
+        &text_scene(&[("\u{2588}\u{2588}", FontId::Bdf("test-block".to_owned()))]),
+        0,
+        &mut canvas,
+    )
+    .unwrap();
+    // Two solid 4×4 blocks, advancing 5px each.
+    assert_eq!(canvas.lit_count(), 32);
+    assert_eq!(canvas.at(0, 0), Rgb888::WHITE);
+    assert_eq!(canvas.at(4, 0), Rgb888::BLACK);
+    assert_eq!(canvas.at(5, 3), Rgb888::WHITE);
+}
+
+/// `TEST_BDF` compiled to PCF, with every table in the byte order
+/// `format` names and the block glyph stored as `bitmap`.
+fn test_pcf(format: u32, compressed_metrics: bool, bitmap: &[u8]) -> Vec<u8> {
+    let msb = format & (1 << 2) != 0;
+    let int = |table: &mut Vec<u8>, value: i64, len: usize| {
+        let bytes = &value.to_be_bytes()[8 - len..];
+        if msb {
+            table.extend(bytes);
+        } else {
+            table.extend(bytes.iter().rev());
+        }
+    };
+    let table = |format: u32| format.to_le_bytes().to_vec();
+    // Left and right bearing, advance, ascent, descent.
+    let metrics = [0, 4, 5, 4, 0];
+
+    let mut accelerators = table(format & (1 << 2));
+    accelerators.extend([0; 8]);
+    int(&mut accelerators, 4, 4);
+    int(&mut accelerators, 0, 4);
+
+    let mut metrics_table;
+    if compressed_metrics {
+        metrics_table = table(0x100 | format & (1 << 2));
+        int(&mut metrics_table, 1, 2);
+        metrics_table.extend(metrics.map(|m| (m + 0x80) as u8));
+    } else {
+        metrics_table = table(format & (1 << 2));
+        int(&mut metrics_table, 1, 4);
+        for m in metrics.into_iter().chain([0]) {
+            int(&mut metrics_table, m, 2);
+        }
+    }
+
+    let mut bitmaps = table(format);
+    int(&mut bitmaps, 1, 4);
+    int(&mut bitmaps, 0, 4);
+    for _ in 0..4 {
+        int(&mut bitmaps, bitmap.len() as i64, 4);
+    }
+    bitmaps.extend(bitmap);
+
+    // Byte 2 and byte 1 ranges covering U+2588 only, no default char.
+    let mut encodings = table(format & (1 << 2));
+    for field in [0x88, 0x88, 0x25, 0x25, 0xffff, 0] {
+        int(&mut encodings, field, 2);
+    }
+
+    let tables = [
+        (1 << 1, accelerators),
+        (1 << 2, metrics_table),
+        (1 << 3, bitmaps),
+        (1 << 5, encodings),
+    ];
+    let mut pcf = b"\x01fcp".to_vec();
+    pcf.extend((tables.len() as u32).to_le_bytes());
+    let mut offset = pcf.len() + 16 * tables.len();
+    for (kind, data) in &tables {
+        for field in [kind, &0, &(data.len() as u32), &(offset as u32)] {
+            pcf.extend(field.to_le_bytes());
+        }
+        offset += data.len();
+    }
+    for (_, data) in tables {
+        pcf.extend(data);
+    }
+    pcf
+}
+
+#[test]
+fn pcf_font_renders_like_its_bdf_source() {
+    let variants = [
+        // LSB-first bytes and bits, compressed metrics, rows padded to 4.
+        ("test-pcf-lsb", test_pcf(0b10, true, &[0x0f, 0, 0, 0].repeat(4))),
+        // MSB-first bytes and bits, unpadded rows.
+        ("test-pcf-msb", test_pcf(0b1100, false, &[0xf0; 4])),
+        // MSB-first bits in LSB-first 4-byte scan units.
+        ("test-pcf-units", test_pcf(0b10_1010, false, &[0, 0, 0, 0xf0].repeat(4))),
+    ];
+    for (name, pcf) in variants {
+        font::register_bdf(name, BdfFont::parse_pcf(&pcf).unwrap());
+        let mut canvas = MockCanvas::new(W, H);
+        render(&text_scene(&[("\u{2588}\u{2588}", FontId::Bdf(name.to_owned()))]), 0, &mut canvas)
+            .unwrap();
+        assert_eq!(canvas.lit_count(), 32, "{name}");
+        assert_eq!(canvas.at(0, 0), Rgb888::WHITE, "{name}");
+        assert_eq!(canvas.at(4, 0), Rgb888::BLACK, "{name}");
+        assert_eq!(canvas.at(5, 3), Rgb888::WHITE, "{name}");
+    }
+}
+
+#[test]
+fn pcf_rejects_other_and_truncated_files() {
+    assert!(BdfFont::parse_pcf(TEST_BDF.as_bytes()).is_err());
+    let pcf = test_pcf(0b1100, false, &[0xf0; 4]);
+    assert!(BdfFont::parse_pcf(&pcf[..pcf.len() - 4]).is_err());
+}
+
+#[test]
+fn unknown_bdf_font_falls_back_to_default() {
+    let mut canvas = MockCanvas::new(W, H);
+    render(&text_scene(&[("HI", FontId::Bdf("missing".to_owned()))]), 0, &mut canvas).unwrap();
+    assert!(canvas.lit_count() > 0);
+}
+
+/* ─── unicode ────────────────────────────────────────────────────── */
+
+fn rendered(text: &str, font: FontId) -> Vec<Rgb888> {
+    let mut canvas = MockCanvas::new(W, H);
+    render(&text_scene(&[(text, font)]), 0, &mut canvas).unwrap();
+    (0..H).flat_map(|y| (0..W).map(move |x| (x, y))).map(|(x, y)| canvas.at(x, y)).collect()
+}
+
+#[test]
+fn non_ascii_letters_get_real_glyphs() {
+    // Latin-1, Latin Extended, Cyrillic and Greek all draw something
+    // other than the replacement `?`.
+    let replacement = rendered("?", FontId::default());
+    for text in ["é", "ł", "ő", "Ж", "Ω", "€"] {
+        let glyph = rendered(text, FontId::default());
+        assert!(glyph.iter().any(|&p| p != Rgb888::BLACK), "{text} is blank");
+        assert_ne!(glyph, replacement, "{text} drew as the replacement glyph");
+    }
+}
+
+#[test]
+fn common_symbols_fall_back_to_builtin_set() {
+    let replacement = rendered("?", FontId::default());
+    for text in ["→", "♥", "★", "✓", "—"] {
+        assert_ne!(rendered(text, FontId::default()), replacement, "{text}");
+    }
+}
+
+#[test]
+fn uncovered_characters_draw_the_replacement_glyph() {
+    assert_eq!(rendered("\u{E000}", FontId::default()), rendered("?", FontId::default()));
+}
+
+#[test]
+fn combining_marks_take_no_space() {
+    let font = font::Font::resolve(&FontId::default());
+    assert_eq!(font.text_width("e\u{301}"), font.text_width("e"));
+    assert_eq!(rendered("❤\u{FE0F}", FontId::default()), rendered("❤", FontId::default()));
+}
+
+const TEST_CJK_BDF: &str = "\
+STARTFONT 2.1
+FONT -test-cjk-medium-r-normal--8-80-75-75-c-80-iso10646-1
+SIZE 8 75 75
+FONTBOUNDINGBOX 7 7 0 0
+STARTPROPERTIES 2
+FONT_ASCENT 7
+FONT_DESCENT 1
+ENDPROPERTIES
+CHARS 1
+STARTCHAR uni65E5
+ENCODING 26085
+SWIDTH 1000 0
+DWIDTH 8 0
+BBX 7 7 0 0
+BITMAP
+FE
+FE
+FE
+FE
+FE
+FE
+FE
+ENDCHAR
+ENDFONT
+";
+
+#[test]
+fn user_fallback_font_covers_cjk_and_is_measured() {
+    font::register_bdf("test-cjk", BdfFont::parse(TEST_CJK_BDF).unwrap());
+    font::set_fallbacks(vec!["test-cjk".to_owned()]);
+
+    let font = font::Font::resolve(&FontId::default());
+    assert_eq!(font.text_width("A日"), font.char_width('A') + 8);
+
+    let mut canvas = MockCanvas::new(W, H);
+    render(&text_scene(&[("日", FontId::default())]), 0, &mut canvas).unwrap();
+    assert_eq!(canvas.lit_count(), 49);
+    // Sits on the 5×8 baseline: bottom row is row 6.
+    assert_eq!(lit_rows(&canvas), Some((0, 6)));
+}
+
+/* ─── layout ─────────────────────────────────────────────────────── */
+
+fn layout_scene(entries: Vec<TextEntry>, layout: TextLayout) -> Scene {
+    scene_with(Mode::Text(display_core::text::TextScene {
+        entries,
+        scroll: 0,
+        layout,
+    }))
+}
+
+/// Columns `[first, last]` containing any lit pixel.
+fn lit_cols(canvas: &MockCanvas) -> Option<(u32, u32)> {
+    let cols: Vec<u32> = (0..W)
+        .filter(|&x| (0..H).any(|y| canvas.at(x, y) != Rgb888::BLACK))
+        .collect();
+    Some((*cols.first()?, *cols.last()?))
+}
+
+#[test]
+fn horizontal_alignment_positions_each_entry() {
+    let cols = |align| {
+        let mut entry = white_entry("HI", FontId::default());
+        entry.options.align = align;
+        let mut canvas = MockCanvas::new(W, H);
+        render(&layout_scene(vec![entry], TextLayout::default()), 0, &mut canvas).unwrap();
+        lit_cols(&canvas).unwrap()
+    };
+    let (left, _) = cols(HorizontalAlign::Left);
+    let (center_first, center_last) = cols(HorizontalAlign::Center);
+    let (_, right) = cols(HorizontalAlign::Right);
+    assert_eq!(left, 0);
+    assert!(right >= W - 2, "right-aligned text ends at column {right}");
+    let (margin_l, margin_r) = (center_first, W - 1 - center_last);
+    assert!(margin_l.abs_diff(margin_r) <= 2, "{margin_l} vs {margin_r}");
+}
+
+#[test]
+fn wrap_breaks_long_entries_between_words() {
+    let text = "THE QUICK BROWN FOX JUMPS";
+    let mut entry = white_entry(text, FontId::default());
+    let mut canvas = MockCanvas::new(W, H);
+    render(&layout_scene(vec![entry.clone()], TextLayout::default()), 0, &mut canvas).unwrap();
+    let (_, unwrapped_bottom) = lit_rows(&canvas).unwrap();
+    assert!(unwrapped_bottom < 9, "one line without wrap");
+
+    entry.options.wrap = true;
+    let mut canvas = MockCanvas::new(W, H);
+    render(&layout_scene(vec![entry], TextLayout::default()), 0, &mut canvas).unwrap();
+    let (_, wrapped_bottom) = lit_rows(&canvas).unwrap();
+    // 25 chars at 5px on a 64px line: "THE QUICK" / "BROWN FOX" /
+    // "JUMPS".
+    assert!(wrapped_bottom > 18, "wrapped onto a third line");
+    // Every line starts with a letter in column 0 — no leading space
+    // carried over from the break.
+    for top in [0, 9, 18] {
+        assert!((top..top + 8).any(|y| (0..2).any(|x| canvas.at(x, y) != Rgb888::BLACK)));
+    }
+}
+
+#[test]
+fn wrap_splits_words_wider_than_the_panel() {
+    let mut entry = white_entry(&"W".repeat(20), FontId::default());
+    entry.options.wrap = true;
+    let mut canvas = MockCanvas::new(W, H);
+    render(&layout_scene(vec![entry], TextLayout::default()), 0, &mut canvas).unwrap();
+    let (_, bottom) = lit_rows(&canvas).unwrap();
+    assert!(bottom > 9, "the word continued on a second line");
+}
+
+#[test]
+fn newlines_start_new_lines() {
+    let mut canvas = MockCanvas::new(W, H);
+    render(&text_scene(&[("A\nB", FontId::default())]), 0, &mut canvas).unwrap();
+    let (_, bottom) = lit_rows(&canvas).unwrap();
+    assert!(bottom > 9, "B on the second line");
+    assert!(lit_cols(&canvas).unwrap().1 < 5, "no `?` drawn for the newline");
+}
+
+#[test]
+fn vertical_alignment_positions_the_block() {
+    let top_row = |vertical_align| {
+        let layout = TextLayout {
+            vertical_align,
+            ..TextLayout::default()
+        };
+        let mut canvas = MockCanvas::new(W, H);
+        render(&layout_scene(vec![white_entry("H", FontId::default())], layout), 0, &mut canvas)
+            .unwrap();
+        lit_rows(&canvas).unwrap().0
+    };
+    let top = top_row(VerticalAlign::Top);
+    assert!(top <= 1, "glyph ink starts at row {top}");
+    // One 8px line: 56px free, half above.
+    assert_eq!(top_row(VerticalAlign::Middle), top + 28);
+    assert_eq!(top_row(VerticalAlign::Bottom), top + 56);
+}
+
+#[test]
+fn line_spacing_sets_the_gap_between_lines() {
+    let layout = TextLayout {
+        line_spacing: 4,
+        ..TextLayout::default()
+    };
+    let mut canvas = MockCanvas::new(W, H);
+    render(&text_scene(&[("H", FontId::default())]), 0, &mut canvas).unwrap();
+    let first_line = lit_rows(&canvas).unwrap().0;
+
+    let entries = vec![white_entry("", FontId::default()), white_entry("H", FontId::default())];
+    let mut canvas = MockCanvas::new(W, H);
+    render(&layout_scene(entries, layout), 0, &mut canvas).unwrap();
+    // 8px line plus 4px gap.
+    assert_eq!(lit_rows(&canvas).unwrap().0, first_line + 12);
+}
+
+/* ─── auto-scroll ────────────────────────────────────────────────── */
+
+/// Ten 5×8 lines (9px pitch) — 89px, more than the 64px panel.
+fn long_list(auto_scroll: AutoScroll) -> Scene {
+    let entries = (0..10).map(|i| white_entry(&format!("LINE {i}"), FontId::default())).collect();
+    layout_scene(
+        entries,
+        TextLayout {
+            auto_scroll,
+            ..TextLayout::default()
+        },
+    )
+}
+
+fn frame_at(scene: &Scene, step: usize) -> MockCanvas {
+    let mut canvas = MockCanvas::new(W, H);
+    render(scene, step, &mut canvas).unwrap();
+    canvas
+}
+
+#[test]
+fn auto_scroll_off_leaves_overflow_static() {
+    let scene = long_list(AutoScroll::Off);
+    assert_eq!(frame_at(&scene, 0).pixels, frame_at(&scene, 500).pixels);
+}
+
+#[test]
+fn smooth_auto_scroll_moves_lines_up() {
+    let scene = long_list(AutoScroll::Smooth { speed: 10, gap: 0 });
+    let (before, after) = (frame_at(&scene, 0), frame_at(&scene, 10));
+    // Ten steps at speed 10 is ten pixels.
+    for y in 0..H - 10 {
+        for x in 0..W {
+            assert_eq!(after.at(x, y), before.at(x, y + 10), "({x}, {y})");
+        }
+    }
+}
+
+#[test]
+fn smooth_auto_scroll_wraps_round_to_the_first_line() {
+    // One full period (89px block + 1px spacing) later it's back
+    // where it started.
+    let scene = long_list(AutoScroll::Smooth { speed: 10, gap: 0 });
+    assert_eq!(frame_at(&scene, 0).pixels, frame_at(&scene, 90).pixels);
+}
+
+#[test]
+fn page_auto_scroll_flips_after_dwell() {
+    let scene = long_list(AutoScroll::Page { dwell_steps: 50 });
+    let first = frame_at(&scene, 0);
+    assert_eq!(first.pixels, frame_at(&scene, 49).pixels, "dwells");
+    let second = frame_at(&scene, 50);
+    assert_ne!(first.pixels, second.pixels, "flipped");
+    // Seven lines fit on the first page; the second holds the other
+    // three, so its bottom rows are dark.
+    assert!(lit_rows(&second).unwrap().1 < 27);
+    // Two pages, then back to the first.
+    assert_eq!(first.pixels, frame_at(&scene, 100).pixels);
+}
+
+#[test]
+fn auto_scroll_ignores_text_that_fits() {
+    let mut scene = long_list(AutoScroll::Smooth { speed: 10, gap: 0 });
+    if let Mode::Text(text) = &mut scene.mode {
+        text.entries.truncate(3);
+    }
+    assert_eq!(frame_at(&scene, 0).pixels, frame_at(&scene, 25).pixels);
+}
+
+/* ─── markup ─────────────────────────────────────────────────────── */
+
+fn markup_frame(text: &str, step: usize) -> MockCanvas {
+    let mut entry = white_entry(text, FontId::default());
+    entry.options.markup = true;
+    frame_at(&layout_scene(vec![entry], TextLayout::default()), step)
+}
+
+fn colors_in_cols(canvas: &MockCanvas, cols: std::ops::Range<u32>) -> Vec<Rgb888> {
+    let mut colors: Vec<Rgb888> = cols
+        .flat_map(|x| (0..H).map(move |y| (x, y)))
+        .map(|(x, y)| canvas.at(x, y))
+        .filter(|&c| c != Rgb888::BLACK)
+        .collect();
+    colors.sort_by_key(|c| (c.r(), c.g(), c.b()));
+    colors.dedup();
+    colors
+}
+
+#[test]
+fn markup_colors_each_span() {
+    let canvas = markup_frame("{red}AA{/}{#00ff00}BB{/}CC", 0);
+    // 5px per letter.
+    assert_eq!(colors_in_cols(&canvas, 0..10), vec![Rgb888::RED]);
+    assert_eq!(colors_in_cols(&canvas, 10..20), vec![Rgb888::GREEN]);
+    assert_eq!(colors_in_cols(&canvas, 20..30), vec![Rgb888::WHITE]);
+}
+
+#[test]
+fn markup_spans_nest_and_inherit() {
+    let spans = display_core::text::parse_markup("{red}a{bold}b{/}c{/}d");
+    let texts: Vec<&str> = spans.iter().map(|s| s.text.as_str()).collect();
+    assert_eq!(texts, ["a", "b", "c", "d"]);
+    assert!(spans[1].style.bold && spans[1].style.color == spans[0].style.color);
+    assert!(!spans[2].style.bold && spans[2].style.color == spans[0].style.color);
+    assert_eq!(spans[3].style, SpanStyle::default());
+}
+
+#[test]
+fn unknown_tags_and_escapes_stay_literal() {
+    let spans = display_core::text::parse_markup("{{x}} {nope} {/} {red");
+    assert_eq!(spans.len(), 1);
+    assert_eq!(spans[0].text, "{x}} {nope} {/} {red");
+}
+
+#[test]
+fn markup_is_opt_in() {
+    let plain = frame_at(&text_scene(&[("{red}A{/}", FontId::default())]), 0);
+    assert_eq!(colors_in_cols(&plain, 0..W), vec![Rgb888::WHITE]);
+    assert!(lit_cols(&plain).unwrap().1 > 20, "braces drawn as text");
+}
+
+#[test]
+fn blink_spans_toggle_with_step() {
+    let on = markup_frame("A{blink}B{/}", 0);
+    let off = markup_frame("A{blink}B{/}", 30);
+    assert_eq!(colors_in_cols(&on, 5..10), vec![Rgb888::WHITE]);
+    assert!(colors_in_cols(&off, 5..10).is_empty());
+    assert_eq!(colors_in_cols(&off, 0..5), vec![Rgb888::WHITE]);
+}
+
+#[test]
+fn inverse_fills_the_cell_behind_the_glyph() {
+    let canvas = markup_frame("{inverse}A{/}", 0);
+    // The 5×8 cell is lit except where the glyph is cut out.
+    let cell_lit = (0..5).flat_map(|x| (0..8).map(move |y| (x, y))).filter(|&(x, y)| {
+        canvas.at(x, y) != Rgb888::BLACK
+    });
+    let lit = cell_lit.count();
+    assert!(lit > 20 && lit < 40, "{lit} of 40 cell pixels lit");
+    assert_eq!(canvas.lit_count(), lit);
+}
+
+#[test]
+fn bold_widens_spans() {
+    let plain = markup_frame("AB", 0);
+    let bold = markup_frame("{bold}AB{/}", 0);
+    assert!(bold.lit_count() > plain.lit_count());
+    // One extra pixel of advance per bold letter.
+    assert_eq!(lit_cols(&bold).unwrap().1, lit_cols(&plain).unwrap().1 + 2);
+}
+
+#[test]
+fn marquee_measures_the_text_without_tags() {
+    let marquee = |text: &str, markup: bool| {
+        let mut entry = white_entry(text, FontId::default());
+        entry.options.markup = markup;
+        entry.options.marquee.speed = 10;
+        frame_at(&layout_scene(vec![entry], TextLayout::default()), 150)
+    };
+    // 15px into a 10px-wide line plus the 64px panel: same position
+    // whether or not the colors come from markup.
+    assert_eq!(
+        lit_cols(&marquee("{red}AB{/}", true)),
+        lit_cols(&marquee("AB", false))
+    );
+}
+
+/* ─── effects ────────────────────────────────────────────────────── */
+
+fn effect_frame(text: &str, effect: TextEffect, step: usize) -> MockCanvas {
+    let mut entry = white_entry(text, FontId::default());
+    entry.options.effect = effect;
+    frame_at(&layout_scene(vec![entry], TextLayout::default()), step)
+}
+
+fn marquee_frame(text: &str, direction: MarqueeDirection, step: usize) -> MockCanvas {
+    let mut entry = white_entry(text, FontId::default());
+    entry.options.marquee.speed = 10;
+    entry.options.marquee.direction = direction;
+    frame_at(&layout_scene(vec![entry], TextLayout::default()), step)
+}
+
+fn aligned_frame(text: &str, align: HorizontalAlign) -> MockCanvas {
+    let mut entry = white_entry(text, FontId::default());
+    entry.options.align = align;
+    frame_at(&layout_scene(vec![entry], TextLayout::default()), 0)
+}
+
+#[test]
+fn typewriter_reveals_one_character_at_a_time() {
+    let typewriter = TextEffect::Typewriter { steps_per_char: 5, hold_steps: 20 };
+    let full = effect_frame("HI", TextEffect::None, 0);
+    assert_eq!(effect_frame("HI", typewriter, 0).lit_count(), 0);
+    let one = effect_frame("HI", typewriter, 5);
+    assert_eq!(one.pixels, effect_frame("H", TextEffect::None, 0).pixels);
+    assert_eq!(effect_frame("HI", typewriter, 10).pixels, full.pixels);
+    assert_eq!(effect_frame("HI", typewriter, 29).pixels, full.pixels);
+    // 2 characters * 5 + 20 held: starts over.
+    assert_eq!(effect_frame("HI", typewriter, 30).lit_count(), 0);
+}
+
+#[test]
+fn typewriter_without_a_hold_still_shows_the_full_text() {
+    let typewriter = TextEffect::Typewriter { steps_per_char: 5, hold_steps: 0 };
+    let full = effect_frame("HI", TextEffect::None, 0);
+    assert_eq!(effect_frame("HI", typewriter, 10).pixels, full.pixels);
+    assert_eq!(effect_frame("HI", typewriter, 14).pixels, full.pixels);
+    // 2 characters * 5 + one character's worth of hold: starts over.
+    assert_eq!(effect_frame("HI", typewriter, 15).lit_count(), 0);
+}
+
+#[test]
+fn fade_ramps_up_holds_and_back_down() {
+    let fade = TextEffect::Fade { fade_steps: 10, hold_steps: 10 };
+    let brightest = |step| {
+        effect_frame("HI", fade, step)
+            .pixels
+            .iter()
+            .map(|p| p.r())
+            .max()
+            .unwrap()
+    };
+    assert_eq!(brightest(0), 0);
+    let mid = brightest(5);
+    assert!(mid > 100 && mid < 155, "expected ~half white, got {mid}");
+    assert_eq!(brightest(10), 255);
+    assert_eq!(brightest(19), 255);
+    assert!(brightest(25) < 155);
+    assert_eq!(brightest(30), 0);
+}
+
+#[test]
+fn scroll_in_rises_into_the_line_row() {
+    let scroll_in = TextEffect::ScrollIn { steps: 8, hold_steps: 8 };
+    let rest = effect_frame("HI", TextEffect::None, 0);
+    assert_eq!(effect_frame("HI", scroll_in, 0).lit_count(), 0, "starts below, clipped");
+    let rising = effect_frame("HI", scroll_in, 4);
+    let (top, bottom) = lit_rows(&rising).unwrap();
+    assert!(top > lit_rows(&rest).unwrap().0, "still on its way up");
+    assert!(bottom < 8, "never drawn below its own 8px row");
+    assert_eq!(effect_frame("HI", scroll_in, 8).pixels, rest.pixels);
+}
+
+#[test]
+fn left_to_right_marquee_mirrors_the_default() {
+    // Starts right-aligned and moves right.
+    let start = marquee_frame("AB", MarqueeDirection::LeftToRight, 0);
+    assert_eq!(start.pixels, aligned_frame("AB", HorizontalAlign::Right).pixels);
+    let (left, _) = lit_cols(&start).unwrap();
+    let later = marquee_frame("AB", MarqueeDirection::LeftToRight, 3);
+    assert_eq!(lit_cols(&later).unwrap().0, left + 3);
+}
+
+#[test]
+fn bounce_marquee_ping_pongs_between_edges() {
+    let text = "ABCDEFGHIJKL";
+    let left = aligned_frame(text, HorizontalAlign::Left);
+    let right = aligned_frame(text, HorizontalAlign::Right);
+    let bounce = |text: &str, step| marquee_frame(text, MarqueeDirection::Bounce, step);
+    let overflow = (0..100).find(|&step| bounce(text, step).pixels == right.pixels).unwrap();
+    assert!(overflow > 0, "text should be wider than the panel");
+    assert_eq!(bounce(text, 0).pixels, left.pixels);
+    assert_eq!(bounce(text, 2 * overflow).pixels, left.pixels);
+    // Narrower text bounces out to the right edge, never leaving the panel.
+    let narrow = aligned_frame("AB", HorizontalAlign::Right);
+    let whole = aligned_frame("AB", HorizontalAlign::Left).lit_count();
+    assert!((0..200).any(|step| bounce("AB", step).pixels == narrow.pixels));
+    assert!((0..200).all(|step| bounce("AB", step).lit_count() == whole));
+}
+
+/* ─── color ──────────────────────────────────────────────────────── */
+
+const RED: Rgb = Rgb { r: 255, g: 0, b: 0 };
+const BLUE: Rgb = Rgb { r: 0, g: 0, b: 255 };
+
+fn gradient(direction: GradientDirection, speed: u32) -> TextEntryColor {
+    TextEntryColor::Gradient(GradientOptions {
+        stops: vec![RED, BLUE],
+        direction,
+        speed,
+    })
+}
+
+fn filled_life(fill: TextEntryColor, step: usize) -> MockCanvas {
+    let scene = scene_with(Mode::Life(LifeScene {
+        color: Rgb { r: 0, g: 255, b: 0 },
+        fill: Some(fill),
+        lattice_width: 64,
+        lattice_height: 64,
+        cells: vec![1; 64 * 64],
+    }));
+    frame_at(&scene, step)
+}
+
+fn colored_text(color: TextEntryColor, step: usize) -> MockCanvas {
+    let mut entry = white_entry("HELLO", FontId::default());
+    entry.options.color = color;
+    frame_at(&layout_scene(vec![entry], TextLayout::default()), step)
+}
+
+#[test]
+fn horizontal_gradient_spans_the_canvas() {
+    let canvas = filled_life(gradient(GradientDirection::Horizontal, 0), 0);
+    assert_eq!(canvas.at(0, 10), Rgb888::RED);
+    assert_eq!(canvas.at(W - 1, 10), Rgb888::BLUE);
+    let mid = canvas.at(W / 2, 10);
+    assert!(mid.r() > 0 && mid.b() > 0, "expected a blend, got {mid:?}");
+}
+
+#[test]
+fn vertical_gradient_runs_top_to_bottom_of_the_text() {
+    let canvas = colored_text(gradient(GradientDirection::Vertical, 0), 0);
+    let (top, bottom) = lit_rows(&canvas).unwrap();
+    let row = |y: u32| (0..W).map(|x| canvas.at(x, y)).find(|&c| c != Rgb888::BLACK).unwrap();
+    assert!(row(top).r() > row(bottom).r());
+    assert!(row(top).b() < row(bottom).b());
+}
+
+#[test]
+fn moving_gradient_scrolls_with_step() {
+    let fill = gradient(GradientDirection::Horizontal, 10);
+    assert_ne!(filled_life(fill.clone(), 0).at(0, 0), filled_life(fill, 16).at(0, 0));
+}
+
+#[test]
+fn palette_steps_through_colors() {
+    let palette = |blend| {
+        TextEntryColor::Palette(PaletteOptions {
+            colors: vec![RED, BLUE],
+            steps_per_color: 10,
+            blend,
+        })
+    };
+    assert_eq!(filled_life(palette(false), 0).at(5, 5), Rgb888::RED);
+    assert_eq!(filled_life(palette(false), 15).at(5, 5), Rgb888::BLUE);
+    assert_eq!(filled_life(palette(false), 20).at(5, 5), Rgb888::RED);
+    let mid = filled_life(palette(true), 5).at(5, 5);
+    assert!(mid.r() > 100 && mid.b() > 100, "expected red/blue blend, got {mid:?}");
+}
+
+#[test]
+fn pulse_breathes_between_two_colors() {
+    let pulse = TextEntryColor::Pulse(PulseOptions {
+        from: RED,
+        to: BLUE,
+        period_steps: 20,
+    });
+    assert!(colors_in_cols(&colored_text(pulse.clone(), 0), 0..W).contains(&Rgb888::RED));
+    assert!(colors_in_cols(&colored_text(pulse.clone(), 10), 0..W).contains(&Rgb888::BLUE));
+    assert!(colors_in_cols(&colored_text(pulse, 20), 0..W).contains(&Rgb888::RED));
+}
+
+#[test]
+fn clock_fill_overrides_solid_color() {
+    let scene = scene_with(Mode::Clock(ClockScene {
+        format: ClockFormat::H24,
+        show_seconds: false,
+        show_meridiem: false,
+        style: ClockStyle::Digital,
+        color: Rgb { r: 0, g: 255, b: 0 },
+        now: ClockTime { hour: 12, minute: 34, second: 56, ..ClockTime::default() },
+        font: FontId::default(),
+        fill: Some(gradient(GradientDirection::Horizontal, 0)),
+        weekday: None,
+        date: None,
+        zones: Vec::new(),
+    }));
+    let canvas = frame_at(&scene, 0);
+    let colors = colors_in_cols(&canvas, 0..W);
+    assert!(colors.iter().all(|c| c.g() == 0), "{colors:?}");
+    let (left, right) = lit_cols(&canvas).unwrap();
+    let first = colors_in_cols(&canvas, left..left + 1)[0];
+    let last = colors_in_cols(&canvas, right..right + 1)[0];
+    assert!(first.r() > last.r() && first.b() < last.b(), "{first:?} .. {last:?}");
+}
+
+/* ─── clock ──────────────────────────────────────────────────────── */
+
+#[test]
+fn clock_renders_nonempty() {
+    let scene = scene_with(Mode::Clock(ClockScene {
+        format: ClockFormat::H24,
+        show_seconds: false,
+        show_meridiem: false,
+        style: ClockStyle::Digital,
+        color: Rgb { r: 255, g: 200, b: 64 },
+        now: ClockTime { hour: 12, minute: 34, second: 56, ..ClockTime::default() },
+        font: FontId::default(),
+        fill: None,
+        weekday: None,
+        date: None,
+        zones: Vec::new(),
+    }));
+    let mut canvas = MockCanvas::new(W, H);
+    render(&scene, 0, &mut canvas).unwrap();
+    assert!(canvas.lit_count() > 0, "clock should render at least the time digits");
+}
+
+fn clock_frame(style: ClockStyle, hour: u8, minute: u8) -> MockCanvas {
+    let scene = scene_with(Mode::Clock(ClockScene {
+        style,
+        now: ClockTime { hour, minute, second: 0, ..ClockTime::default() },
+        ..ClockScene::default()
+    }));
+    frame_at(&scene, 0)
+}
+
+#[test]
+fn segment_clock_fills_the_panel() {
+    let (top, bottom) = lit_rows(&clock_frame(ClockStyle::Segment, 12, 34)).unwrap();
+    let (left, right) = lit_cols(&clock_frame(ClockStyle::Segment, 12, 34)).unwrap();
+    assert!(bottom - top >= 20, "digits should be far taller than the 8px font");
+    assert!(right - left >= 48, "digits should span most of the width");
+    let (digital_top, digital_bottom) = lit_rows(&clock_frame(ClockStyle::Digital, 12, 34)).unwrap();
+    assert!(bottom - top > 2 * (digital_bottom - digital_top));
+    assert_ne!(
+        clock_frame(ClockStyle::Segment, 12, 34).pixels,
+        clock_frame(ClockStyle::Segment, 12, 35).pixels
+    );
+}
+
+#[test]
+fn analog_hands_point_at_the_time() {
+    let (cx, cy) = (W / 2, H / 2);
+    let lit = |canvas: &MockCanvas, x, y| canvas.at(x, y) != Rgb888::BLACK;
+    let three = clock_frame(ClockStyle::Analog, 3, 0);
+    assert!(lit(&three, cx, cy - 15), "minute hand up at :00");
+    assert!(lit(&three, cx + 10, cy), "hour hand right at 3");
+    assert!(!lit(&three, cx - 10, cy));
+    let nine = clock_frame(ClockStyle::Analog, 21, 0);
+    assert!(lit(&nine, cx - 10, cy), "hour hand left at 9");
+    assert!(!lit(&nine, cx + 10, cy));
+}
+
+#[test]
+fn binary_clock_fills_set_bits() {
+    // Four 16px columns (H H M M), bit 0 on the bottom row; the middle
+    // of the hour-units bottom cell is only lit when that bit is set.
+    let middle = (16 + 8, 48 + 8);
+    assert_eq!(clock_frame(ClockStyle::Binary, 0, 0).at(middle.0, middle.1), Rgb888::BLACK);
+    assert_ne!(clock_frame(ClockStyle::Binary, 1, 0).at(middle.0, middle.1), Rgb888::BLACK);
+    assert!(
+        clock_frame(ClockStyle::Binary, 0, 0).lit_count() > 0,
+        "unset bits still show as outlines"
+    );
+}
+
+#[test]
+fn weekday_and_date_lines_sit_below_the_time() {
+    let now = ClockTime { hour: 9, minute: 41, second: 0, year: 2026, month: 10, day: 18, weekday: 6 };
+    let plain = ClockSceneConfig::default().into_frame(now, &[]);
+    let dated = ClockSceneConfig {
+        weekday: Some(WeekdayFormat::Short),
+        date: Some(DateFormat::DayMonth),
+        ..ClockSceneConfig::default()
+    }
+    .into_frame(now, &[]);
+    let (_, plain_bottom) = lit_rows(&frame_at(&scene_with(Mode::Clock(plain)), 0)).unwrap();
+    let dated_canvas = frame_at(&scene_with(Mode::Clock(dated)), 0);
+    let (_, dated_bottom) = lit_rows(&dated_canvas).unwrap();
+    assert!(dated_bottom > plain_bottom + 8, "two more lines below the time");
+    assert!(dated_bottom >= H - 9, "date lines run along the bottom");
+
+    // "SUN" then "18 OCT", each centered in the default font.
+    let lines = |weekday, date| {
+        let config = ClockSceneConfig { weekday, date, ..ClockSceneConfig::default() };
+        frame_at(&scene_with(Mode::Clock(config.into_frame(now, &[]))), 0)
+    };
+    assert_ne!(
+        lines(Some(WeekdayFormat::Short), None).pixels,
+        lines(Some(WeekdayFormat::Long), None).pixels
+    );
+    assert_ne!(lines(None, Some(DateFormat::Iso)).pixels, lines(None, Some(DateFormat::DayMonth)).pixels);
+}
+
+#[test]
+fn world_clock_draws_a_row_per_zone() {
+    let zone = |label: &str| ClockZone { label: label.to_owned(), timezone: "UTC".to_owned() };
+    let config = ClockSceneConfig {
+        zones: vec![zone("SF"), zone("NYC"), zone("LON")],
+        ..ClockSceneConfig::default()
+    };
+    let at = |hour| ClockTime { hour, ..ClockTime::default() };
+    let scene = config.into_frame(at(0), &[at(9), at(12), at(17)]);
+    assert_eq!(scene.zones.len(), 3);
+    assert_eq!(scene.zones[1].label, "NYC");
+    assert_eq!(scene.zones[2].now.hour, 17);
+    let canvas = frame_at(&scene_with(Mode::Clock(scene)), 0);
+    let (top, bottom) = lit_rows(&canvas).unwrap();
+    assert!(bottom - top >= 2 * 9, "three stacked rows");
+    let (left, right) = lit_cols(&canvas).unwrap();
+    assert!(left <= 1 && right >= W - 2, "labels left, times right");
+}
+
+#[test]
+fn word_clock_rounds_to_five_minutes() {
+    let words = |hour, minute, second| {
+        clock::time_in_words(ClockTime { hour, minute, second, ..ClockTime::default() })
+    };
+    assert_eq!(words(10, 0, 0), "IT IS TEN O'CLOCK");
+    assert_eq!(words(22, 14, 0), "IT IS QUARTER PAST TEN");
+    assert_eq!(words(10, 33, 0), "IT IS TWENTY FIVE TO ELEVEN");
+    assert_eq!(words(11, 57, 30), "IT IS TWELVE O'CLOCK");
+    assert_eq!(words(0, 30, 0), "IT IS HALF PAST TWELVE");
+    let (top, bottom) = lit_rows(&clock_frame(ClockStyle::Words, 10, 15)).unwrap();
+    assert!(bottom - top > 16, "phrase should wrap onto several lines");
+}
+
+/* ─── countdown ──────────────────────────────────────────────────── */
+
+fn countdown_frame(
+    remaining_ms: i64,
+    precision: CountdownPrecision,
+    on_finish: CountdownFinish,
+    step: usize,
+) -> MockCanvas {
+    let scene = scene_with(Mode::Countdown(CountdownScene {
+        remaining_ms,
+        precision,
+        on_finish,
+        ..CountdownScene::default()
+    }));
+    frame_at(&scene, step)
+}
+
+fn seconds(remaining_ms: i64) -> MockCanvas {
+    countdown_frame(remaining_ms, CountdownPrecision::Seconds, CountdownFinish::Hold, 0)
+}
+
+#[test]
+fn countdown_target_comes_from_timestamp_or_duration() {
+    let timer = CountdownSceneConfig {
+        duration_secs: Some(300),
+        started_at: Some(1_000),
+        ..CountdownSceneConfig::default()
+    };
+    assert_eq!(timer.target_ms(), Some(1_300_000));
+    assert_eq!(timer.clone().into_frame(1_200_000).remaining_ms, 100_000);
+    let launch = CountdownSceneConfig { target: Some(2_000), ..timer };
+    assert_eq!(launch.target_ms(), Some(2_000_000), "an explicit target wins");
+    assert_eq!(CountdownSceneConfig::default().into_frame(5).remaining_ms, 0);
+}
+
+#[test]
+fn countdown_rounds_up_to_its_precision() {
+    assert!(seconds(65_000).lit_count() > 0);
+    assert_eq!(seconds(65_000).pixels, seconds(64_001).pixels);
+    assert_ne!(seconds(65_000).pixels, seconds(64_000).pixels);
+    let tenths = |ms| countdown_frame(ms, CountdownPrecision::Tenths, CountdownFinish::Hold, 0);
+    assert_ne!(tenths(64_500).pixels, tenths(64_400).pixels);
+    let minutes = |ms| countdown_frame(ms, CountdownPrecision::Minutes, CountdownFinish::Hold, 0);
+    assert_eq!(minutes(61_000).pixels, minutes(119_000).pixels);
+}
+
+#[test]
+fn countdown_holds_at_zero_by_default() {
+    assert_eq!(seconds(-5_000).pixels, seconds(0).pixels);
+}
+
+#[test]
+fn countdown_can_count_up_after_the_target() {
+    let up = |ms| countdown_frame(ms, CountdownPrecision::Seconds, CountdownFinish::CountUp, 0);
+    assert_eq!(up(5_000).pixels, seconds(5_000).pixels, "counts down as usual first");
+    assert_ne!(up(-5_000).pixels, seconds(0).pixels);
+    assert_ne!(up(-5_000).pixels, up(-7_000).pixels);
+    let (left, _) = lit_cols(&up(-5_000)).unwrap();
+    assert!(left < lit_cols(&seconds(5_000)).unwrap().0, "wider for the leading +");
+}
+
+#[test]
+fn countdown_survives_extreme_targets() {
+    let far = CountdownSceneConfig { target: Some(i64::MAX / 1000), ..CountdownSceneConfig::default() };
+    assert_eq!(far.into_frame(i64::MIN).remaining_ms, i64::MAX);
+    for precision in [CountdownPrecision::Minutes, CountdownPrecision::Seconds, CountdownPrecision::Tenths] {
+        for on_finish in [CountdownFinish::Hold, CountdownFinish::CountUp] {
+            for ms in [i64::MAX, i64::MIN, i64::MIN + 1] {
+                assert!(countdown_frame(ms, precision, on_finish.clone(), 0).lit_count() > 0);
+            }
+        }
+    }
+}
+
+#[test]
+fn countdown_finish_flashes_or_shows_a_message() {
+    let flash = CountdownFinish::Flash(FlashState { is_active: true, on_steps: 5, total_steps: 10 });
+    let flashing = |ms, step| countdown_frame(ms, CountdownPrecision::Seconds, flash.clone(), step);
+    assert_eq!(flashing(0, 0).lit_count(), (W * H) as usize);
+    assert_eq!(flashing(0, 5).pixels, seconds(0).pixels);
+    assert_eq!(flashing(1_000, 0).pixels, seconds(1_000).pixels, "no flash before the end");
+
+    let message = CountdownFinish::Message("LIFTOFF".to_owned());
+    let done = countdown_frame(-1, CountdownPrecision::Seconds, message, 0);
+    let (left, right) = lit_cols(&done).unwrap();
+    let (zero_left, zero_right) = lit_cols(&seconds(0)).unwrap();
+    assert!(right - left > zero_right - zero_left, "seven letters are wider than 00:00");
+}
+
+#[test]
+fn countdown_label_sits_above_the_time() {
+    let labeled = scene_with(Mode::Countdown(CountdownScene {
+        remaining_ms: 60_000,
+        label: "STANDUP".to_owned(),
+        ..CountdownScene::default()
+    }));
+    let (top, bottom) = lit_rows(&frame_at(&labeled, 0)).unwrap();
+    let (plain_top, plain_bottom) = lit_rows(&seconds(60_000)).unwrap();
+    assert!(top < plain_top && bottom > plain_bottom - 8);
+    assert!(bottom - top > 12, "two lines tall");
+}
+
+/* ─── chart ──────────────────────────────────────────────────────── */
+
+fn chart_frame(samples: Vec<f32>, style: ChartStyle) -> MockCanvas {
+    frame_at(&chart_scene(ChartScene { samples, style, ..ChartScene::default() }), 0)
+}
+
+fn chart_scene(chart: ChartScene) -> Scene {
+    scene_with(Mode::Chart(chart))
+}
+
+#[test]
+fn sparkline_auto_scales_to_the_full_height() {
+    let canvas = chart_frame(vec![0.0, 10.0], ChartStyle::Sparkline);
+    assert_eq!(lit_cols(&canvas), Some((62, 63)), "newest sample sits at the right edge");
+    assert_ne!(canvas.at(62, H - 1), Rgb888::BLACK, "lowest sample on the bottom row");
+    assert_ne!(canvas.at(63, 0), Rgb888::BLACK, "highest sample on the top row");
+    assert!((0..H - 1).all(|y| canvas.at(63, y) != Rgb888::BLACK), "steps stay joined");
+}
+
+#[test]
+fn chart_keeps_only_the_newest_samples_that_fit() {
+    let canvas = chart_frame((0..100u8).map(f32::from).collect(), ChartStyle::Sparkline);
+    assert_eq!(lit_cols(&canvas), Some((0, W - 1)));
+    assert_ne!(canvas.at(0, H - 1), Rgb888::BLACK, "oldest visible sample sets the floor");
+    assert_ne!(canvas.at(W - 1, 0), Rgb888::BLACK);
+}
+
+#[test]
+fn area_fills_down_to_a_pinned_axis() {
+    let canvas = frame_at(
+        &chart_scene(ChartScene {
+            samples: vec![5.0; W as usize],
+            style: ChartStyle::Area,
+            min: Some(0.0),
+            max: Some(10.0),
+            ..ChartScene::default()
+        }),
         0,
-        "paused panel should suppress the flash overlay"
     );
+    let (top, bottom) = lit_rows(&canvas).unwrap();
+    assert!((30..=33).contains(&top), "half-scale reaches mid-panel, got {top}");
+    assert_eq!(bottom, H - 1);
+    assert_eq!(canvas.lit_count(), (W * (H - top)) as usize, "every column filled below");
 }
 
-/* ─── text ───────────────────────────────────────────────────────── */
+#[test]
+fn bars_leave_a_gap_between_samples() {
+    let canvas = chart_frame(vec![1.0, 2.0, 3.0], ChartStyle::Bar);
+    let lit = |x| (0..H).any(|y| canvas.at(x, y) != Rgb888::BLACK);
+    let lit_xs: Vec<u32> = (0..W).filter(|&x| lit(x)).collect();
+    assert_eq!(lit_xs, vec![52, 53, 54, 56, 57, 58, 60, 61, 62]);
+    assert_ne!(canvas.at(60, 0), Rgb888::BLACK, "tallest bar reaches the top");
+    assert_eq!(canvas.at(52, H - 2), Rgb888::BLACK, "shortest bar is one row");
+}
 
 #[test]
-fn text_entry_renders_some_pixels() {
-    let entries = vec![TextEntry {
-        text: "hi".into(),
-        options: TextEntryOptions {
-            color: TextEntryColor::Rgb(Rgb { r: 255, g: 138, b: 44 }),
-            marquee: display_core::MarqueeOptions { speed: 0 },
-        },
-    }];
-    let scene = scene_with(Mode::Text(display_core::text::TextScene {
-        entries,
-        scroll: 0,
-    }));
-    let mut canvas = MockCanvas::new(W, H);
-    render(&scene, 0, &mut canvas).unwrap();
-    assert!(
-        canvas.lit_count() > 0,
-        "rendered text should light up at least some pixels"
+fn thresholds_color_everything_above_them() {
+    let red = Rgb { r: 255, g: 0, b: 0 };
+    let canvas = frame_at(
+        &chart_scene(ChartScene {
+            samples: vec![0.0, 10.0],
+            style: ChartStyle::Area,
+            thresholds: vec![ChartThreshold { value: 5.0, color: red }],
+            ..ChartScene::default()
+        }),
+        0,
     );
+    assert_eq!(canvas.at(63, 0), red.into());
+    assert_eq!(canvas.at(63, H - 1), ChartScene::default().color.into());
+    assert_eq!(colors_in_cols(&canvas, 63..64).len(), 2);
 }
 
-/* ─── clock ──────────────────────────────────────────────────────── */
+#[test]
+fn labels_take_a_gutter_left_of_the_plot() {
+    let samples: Vec<f32> = (0..100u8).map(f32::from).collect();
+    let labeled = frame_at(
+        &chart_scene(ChartScene { samples: samples.clone(), labels: true, ..ChartScene::default() }),
+        0,
+    );
+    let label_color: Rgb888 = ChartScene::default().label_color.into();
+    assert_eq!(colors_in_cols(&labeled, 0..4), vec![label_color]);
+    assert_ne!(labeled.pixels, chart_frame(samples, ChartStyle::Sparkline).pixels);
+    assert!((0..H).all(|y| labeled.at(0, y) != ChartScene::default().color.into()));
+}
 
 #[test]
-fn clock_renders_nonempty() {
-    let scene = scene_with(Mode::Clock(ClockScene {
-        format: ClockFormat::H24,
-        show_seconds: false,
-        show_meridiem: false,
-        color: Rgb { r: 255, g: 200, b: 64 },
-        now: ClockTime { hour: 12, minute: 34, second: 56 },
-    }));
+fn empty_chart_draws_a_baseline() {
+    let canvas = chart_frame(Vec::new(), ChartStyle::Sparkline);
+    assert_eq!(lit_rows(&canvas), Some((H - 1, H - 1)));
+    assert_eq!(canvas.lit_count(), W as usize);
+}
+
+/* ─── gauge ──────────────────────────────────────────────────────── */
+
+fn gauge_frame(style: GaugeStyle, value: f32) -> MockCanvas {
+    let gauge = GaugeScene { style, value, show_value: false, ..GaugeScene::default() };
+    frame_at(&scene_with(Mode::Gauge(gauge)), 0)
+}
+
+/// Pixels of `color` on the canvas, as `(x, y)`.
+fn pixels_of(canvas: &MockCanvas, color: Rgb) -> Vec<(u32, u32)> {
+    let color: Rgb888 = color.into();
+    (0..H)
+        .flat_map(|y| (0..W).map(move |x| (x, y)))
+        .filter(|&(x, y)| canvas.at(x, y) == color)
+        .collect()
+}
+
+#[test]
+fn gauge_fraction_clamps_to_the_range() {
+    let gauge = |value, min, max| GaugeScene { value, min, max, ..GaugeScene::default() }.fraction();
+    assert_eq!(gauge(25.0, 0.0, 100.0), 0.25);
+    assert_eq!(gauge(15.0, 10.0, 30.0), 0.25);
+    assert_eq!(gauge(150.0, 0.0, 100.0), 1.0);
+    assert_eq!(gauge(-5.0, 0.0, 100.0), 0.0);
+    assert_eq!(gauge(5.0, 10.0, 10.0), 0.0, "an empty range reads as empty");
+}
+
+#[test]
+fn bar_gauge_fills_left_to_right() {
+    let color = GaugeScene::default().color;
+    let half = pixels_of(&gauge_frame(GaugeStyle::Bar, 50.0), color);
+    let cols: Vec<u32> = half.iter().map(|&(x, _)| x).collect();
+    assert_eq!((cols.iter().min(), cols.iter().max()), (Some(&2), Some(&31)));
+    let full = pixels_of(&gauge_frame(GaugeStyle::Bar, 100.0), color);
+    assert_eq!(full.len(), 2 * half.len());
+    assert!(pixels_of(&gauge_frame(GaugeStyle::Bar, 0.0), color).is_empty());
+    let track = GaugeScene::default().track_color;
+    assert_eq!(pixels_of(&gauge_frame(GaugeStyle::Bar, 0.0), track).len(), full.len());
+}
+
+#[test]
+fn gauge_thresholds_recolor_the_fill() {
+    let red = Rgb { r: 255, g: 0, b: 0 };
+    let gauge = |value| {
+        let gauge = GaugeScene {
+            value,
+            thresholds: vec![ChartThreshold { value: 80.0, color: red }],
+            ..GaugeScene::default()
+        };
+        frame_at(&scene_with(Mode::Gauge(gauge)), 0)
+    };
+    assert!(pixels_of(&gauge(50.0), red).is_empty());
+    assert!(!pixels_of(&gauge(90.0), red).is_empty());
+    assert!(pixels_of(&gauge(90.0), GaugeScene::default().color).is_empty());
+}
+
+#[test]
+fn ring_gauge_fills_clockwise_from_the_top() {
+    let color = GaugeScene::default().color;
+    let quarter = pixels_of(&gauge_frame(GaugeStyle::Ring, 25.0), color);
+    assert!(!quarter.is_empty());
+    assert!(quarter.iter().all(|&(x, y)| x >= W / 2 - 1 && y <= H / 2 + 1), "top-right quadrant");
+    let lit = |value| pixels_of(&gauge_frame(GaugeStyle::Ring, value), color).len();
+    assert!(lit(75.0) > 2 * lit(25.0));
+}
+
+#[test]
+fn dial_gauge_sweeps_over_the_top() {
+    let canvas = gauge_frame(GaugeStyle::Dial, 100.0);
+    let (top, bottom) = lit_rows(&canvas).unwrap();
+    assert!(bottom - top <= H / 2, "a half circle, got rows {top}..{bottom}");
+    let color = GaugeScene::default().color;
+    let half = pixels_of(&gauge_frame(GaugeStyle::Dial, 50.0), color);
+    assert!(half.iter().all(|&(x, _)| x <= W / 2), "half full covers the left half");
+}
+
+#[test]
+fn gauge_prints_its_value_and_label() {
+    let plain = gauge_frame(GaugeStyle::Bar, 42.0);
+    let labeled = GaugeScene { value: 42.0, label: "DISK".to_owned(), unit: "%".to_owned(), ..GaugeScene::default() };
+    let labeled = frame_at(&scene_with(Mode::Gauge(labeled)), 0);
+    let (top, bottom) = lit_rows(&labeled).unwrap();
+    let (plain_top, plain_bottom) = lit_rows(&plain).unwrap();
+    assert!(top < plain_top && bottom > plain_bottom, "label above, value below the bar");
+}
+
+/* ─── weather ────────────────────────────────────────────────────── */
+
+fn weather_frame(report: Option<WeatherReport>, units: TemperatureUnit, show_range: bool) -> MockCanvas {
+    let scene = scene_with(Mode::Weather(WeatherScene { report, units, show_range, ..WeatherScene::default() }));
+    frame_at(&scene, 0)
+}
+
+fn report(condition: WeatherCondition, temperature: f32) -> WeatherReport {
+    WeatherReport { condition, is_day: true, temperature, high: temperature + 3.0, low: temperature - 3.0 }
+}
+
+#[test]
+fn weather_without_a_report_shows_a_placeholder() {
+    let waiting = weather_frame(None, TemperatureUnit::Celsius, true);
+    let (top, bottom) = lit_rows(&waiting).unwrap();
+    assert!(bottom - top < 8, "just the temperature line, no icon or range");
+}
+
+#[test]
+fn every_condition_has_its_own_icon() {
+    let conditions = [
+        WeatherCondition::Clear,
+        WeatherCondition::PartlyCloudy,
+        WeatherCondition::Cloudy,
+        WeatherCondition::Fog,
+        WeatherCondition::Drizzle,
+        WeatherCondition::Rain,
+        WeatherCondition::Snow,
+        WeatherCondition::Thunderstorm,
+    ];
+    let frames: Vec<Vec<Rgb888>> = conditions
+        .iter()
+        .map(|&c| weather_frame(Some(report(c, 20.0)), TemperatureUnit::Celsius, true).pixels)
+        .collect();
+    for (i, a) in frames.iter().enumerate() {
+        for b in &frames[i + 1..] {
+            assert_ne!(a, b);
+        }
+    }
+    let (top, _) = lit_rows(&weather_frame(Some(report(WeatherCondition::Clear, 20.0)), TemperatureUnit::Celsius, true)).unwrap();
+    let (text_top, _) = lit_rows(&weather_frame(None, TemperatureUnit::Celsius, true)).unwrap();
+    assert!(text_top - top >= 32, "icon scales to 32px on a 64px panel");
+}
+
+#[test]
+fn night_swaps_the_sun_for_the_moon() {
+    let day = report(WeatherCondition::Clear, 20.0);
+    let night = WeatherReport { is_day: false, ..day };
+    let sun = Rgb { r: 0xff, g: 0xc8, b: 0x00 };
+    let day = weather_frame(Some(day), TemperatureUnit::Celsius, true);
+    let night = weather_frame(Some(night), TemperatureUnit::Celsius, true);
+    assert!(colors_in_cols(&day, 0..W).contains(&sun.into()));
+    assert!(!colors_in_cols(&night, 0..W).contains(&sun.into()));
+}
+
+#[test]
+fn fahrenheit_converts_from_celsius() {
+    let twenty_c = Some(report(WeatherCondition::Cloudy, 20.0));
+    let sixty_eight = Some(report(WeatherCondition::Cloudy, 68.0));
+    assert_eq!(
+        weather_frame(twenty_c, TemperatureUnit::Fahrenheit, false).pixels,
+        weather_frame(sixty_eight, TemperatureUnit::Celsius, false).pixels,
+    );
+    assert_ne!(
+        weather_frame(twenty_c, TemperatureUnit::Celsius, false).pixels,
+        weather_frame(sixty_eight, TemperatureUnit::Celsius, false).pixels,
+    );
+}
+
+#[test]
+fn high_and_low_sit_under_the_temperature() {
+    let rain = Some(report(WeatherCondition::Rain, 12.0));
+    let (_, with_range) = lit_rows(&weather_frame(rain, TemperatureUnit::Celsius, true)).unwrap();
+    let (_, without) = lit_rows(&weather_frame(rain, TemperatureUnit::Celsius, false)).unwrap();
+    assert!(with_range > without);
+}
+
+/* ─── orientation ────────────────────────────────────────────────── */
+
+fn oriented(pattern: TestPattern, rotation: u16, flip_h: bool, flip_v: bool, w: u32, h: u32) -> MockCanvas {
+    let mut scene = scene_with(Mode::Test(TestScene { pattern }));
+    scene.panel.rotation = rotation;
+    scene.panel.flip_horizontal = flip_h;
+    scene.panel.flip_vertical = flip_v;
+    let mut canvas = MockCanvas::new(w, h);
+    render(&scene, 0, &mut canvas).unwrap();
+    canvas
+}
+
+#[test]
+fn quarter_turn_lays_out_on_the_rotated_size() {
+    // 64×32 landscape turned to portrait: the renderer sees 32×64, so
+    // its eight 4-wide bars run down the physical panel as 4-tall
+    // stripes, first bar at the top.
+    let canvas = oriented(TestPattern::ColorBars, 90, false, false, 64, 32);
+    assert_eq!(canvas.at(10, 5), Rgb888::RED);
+    assert_eq!(canvas.at(10, 30), Rgb888::WHITE);
+    let size = PanelState { rotation: 90, ..PanelState::default() }.logical_size(Size::new(64, 32));
+    assert_eq!(size, Size::new(32, 64));
+}
+
+#[test]
+fn half_turn_and_mirror_reverse_the_bars() {
+    let plain = oriented(TestPattern::ColorBars, 0, false, false, W, H);
+    assert_eq!(plain.at(10, 20), Rgb888::RED);
+    for canvas in [
+        oriented(TestPattern::ColorBars, 180, false, false, W, H),
+        oriented(TestPattern::ColorBars, 0, true, false, W, H),
+    ] {
+        assert_eq!(canvas.at(W - 1 - 10, 20), Rgb888::RED);
+        assert_eq!(canvas.at(10, 20), Rgb888::MAGENTA);
+    }
+}
+
+#[test]
+fn vertical_flip_puts_the_top_band_at_the_bottom() {
+    let canvas = oriented(TestPattern::Gradient, 0, false, true, W, H);
+    assert_eq!(canvas.at(W - 1, H - 1), Rgb888::new(255, 0, 0));
+    assert_eq!(canvas.at(W - 1, 0), Rgb888::new(0, 0, 255));
+}
+
+#[test]
+fn rotation_rounds_down_to_a_quarter_turn() {
+    let canvas = oriented(TestPattern::ColorBars, 100, false, false, 64, 32);
+    assert_eq!(canvas.at(10, 5), Rgb888::RED);
+}
+
+/* ─── calibration ────────────────────────────────────────────────── */
+
+#[test]
+fn default_calibration_changes_nothing() {
+    let calibration = Calibration::default();
+    assert!(calibration.is_identity());
+    let lut = calibration.lut();
+    for c in [Rgb888::BLACK, Rgb888::new(255, 138, 44), Rgb888::new(1, 2, 3), Rgb888::WHITE] {
+        assert_eq!(lut.apply(c), c);
+    }
+}
+
+#[test]
+fn gain_balances_channels_and_gamma_darkens_midtones() {
+    let lut = Calibration {
+        gain: Channels { r: 1.0, g: 0.5, b: 0.25 },
+        ..Calibration::default()
+    }
+    .lut();
+    assert_eq!(lut.apply(Rgb888::WHITE), Rgb888::new(255, 128, 64));
+
+    let lut = Calibration {
+        gamma: Channels::all(2.0),
+        ..Calibration::default()
+    }
+    .lut();
+    assert_eq!(lut.apply(Rgb888::new(128, 128, 128)), Rgb888::new(64, 64, 64));
+    assert_eq!(lut.apply(Rgb888::WHITE), Rgb888::WHITE);
+}
+
+#[test]
+fn black_level_lifts_lit_values_but_keeps_black() {
+    let lut = Calibration {
+        black_level: 10,
+        ..Calibration::default()
+    }
+    .lut();
+    assert_eq!(lut.apply(Rgb888::BLACK), Rgb888::BLACK);
+    assert_eq!(lut.apply(Rgb888::new(1, 0, 0)), Rgb888::new(11, 0, 0));
+    assert_eq!(lut.apply(Rgb888::WHITE), Rgb888::WHITE);
+}
+
+#[test]
+fn calibration_channels_take_one_number_or_three() {
+    let c: Calibration =
+        serde_json::from_str(r#"{"gain": 0.5, "gamma": {"r": 2.2, "g": 2.0, "b": 1.8}}"#).unwrap();
+    assert_eq!(c.gain, Channels::all(0.5));
+    assert_eq!(c.gamma, Channels { r: 2.2, g: 2.0, b: 1.8 });
+    assert_eq!(c.black_level, 0);
+}
+
+#[test]
+fn calibration_pattern_steps_grey_then_near_black_then_swatches() {
     let mut canvas = MockCanvas::new(W, H);
+    let scene = scene_with(Mode::Test(TestScene { pattern: TestPattern::Calibration }));
     render(&scene, 0, &mut canvas).unwrap();
-    assert!(canvas.lit_count() > 0, "clock should render at least the time digits");
+    let greys: Vec<Rgb888> = (0..8).map(|i| canvas.at(i * W / 8, 0)).collect();
+    assert!(greys.windows(2).all(|w| w[0].r() < w[1].r()), "{greys:?}");
+    assert!(greys.iter().all(|c| c.r() == c.g() && c.g() == c.b()));
+    assert_eq!(canvas.at(W - 1, H / 2), Rgb888::new(12, 12, 12));
+    assert_eq!(canvas.at(0, H - 1), Rgb888::new(255, 138, 44));
+    assert_eq!(canvas.at(W - 1, H - 1), Rgb888::WHITE);
 }
 
 /* ─── image / paint ──────────────────────────────────────────────── */
@@ -554,6 +1956,76 @@ fn brightness_missing_json_field_defaults_full() {
     assert_eq!(scene.panel.brightness, 1.0);
 }
 
+fn dimmed(pattern: TestPattern, brightness: f32, curve: BrightnessCurve, dither: bool, step: usize) -> MockCanvas {
+    let mut scene = scene_with(Mode::Test(TestScene { pattern }));
+    scene.panel.brightness = brightness;
+    scene.panel.brightness_curve = curve;
+    scene.panel.dither = dither;
+    let mut canvas = MockCanvas::new(W, H);
+    render(&scene, step, &mut canvas).unwrap();
+    canvas
+}
+
+/// Leftmost lit column in the top rows of the gradient pattern: how
+/// far down its black-to-red ramp light survives dimming.
+fn dimmest_lit_col(canvas: &MockCanvas) -> u32 {
+    (0..W).find(|&x| (0..4).any(|y| canvas.at(x, y).r() > 0)).unwrap_or(W)
+}
+
+#[test]
+fn undithered_dimming_truncates() {
+    // 10% of 255 is 25.5, and 50% is 127.5: both take the level below.
+    let tenth = dimmed(TestPattern::ColorBars, 0.1, BrightnessCurve::Linear, false, 0);
+    assert_eq!(tenth.at(10, 20), Rgb888::new(25, 0, 0));
+    let half = dimmed(TestPattern::ColorBars, 0.5, BrightnessCurve::Linear, false, 0);
+    assert_eq!(half.at(10, 20), Rgb888::new(127, 0, 0));
+}
+
+#[test]
+fn perceptual_curve_keeps_dim_colors_lit() {
+    let linear = dimmed(TestPattern::Gradient, 0.02, BrightnessCurve::Linear, false, 0);
+    let perceptual = dimmed(TestPattern::Gradient, 0.02, BrightnessCurve::Perceptual, false, 0);
+    assert!(
+        dimmest_lit_col(&perceptual) * 2 < dimmest_lit_col(&linear),
+        "perceptual {} vs linear {}",
+        dimmest_lit_col(&perceptual),
+        dimmest_lit_col(&linear),
+    );
+    let full = dimmed(TestPattern::ColorBars, 1.0, BrightnessCurve::Perceptual, false, 0);
+    assert_eq!(full.at(10, 20), Rgb888::RED);
+}
+
+#[test]
+fn dithering_averages_to_the_exact_level() {
+    // 10% of 255 is 25.5: half the pixels of each frame round up, and
+    // each pixel rounds up on half the frames.
+    let frame = dimmed(TestPattern::ColorBars, 0.1, BrightnessCurve::Linear, true, 0);
+    let tile: Vec<u8> = (0..4)
+        .flat_map(|y| (8..12).map(move |x| (x, y)))
+        .map(|(x, y)| frame.at(x, y).r())
+        .collect();
+    assert!(tile.iter().all(|&r| r == 25 || r == 26), "{tile:?}");
+    assert_eq!(tile.iter().filter(|&&r| r == 26).count(), 8);
+
+    let over_time: u32 = (0..16)
+        .map(|step| dimmed(TestPattern::ColorBars, 0.1, BrightnessCurve::Linear, true, step))
+        .map(|frame| u32::from(frame.at(10, 20).r()))
+        .sum();
+    assert_eq!(over_time, 25 * 16 + 8);
+}
+
+#[test]
+fn dithering_lights_levels_that_round_to_black() {
+    let rounded = dimmed(TestPattern::Gradient, 0.01, BrightnessCurve::Linear, false, 0);
+    let dithered = dimmed(TestPattern::Gradient, 0.01, BrightnessCurve::Linear, true, 0);
+    assert!(dimmest_lit_col(&dithered) < dimmest_lit_col(&rounded));
+    assert_ne!(
+        dithered.pixels,
+        dimmed(TestPattern::Gradient, 0.01, BrightnessCurve::Linear, true, 1).pixels,
+        "the dither pattern should move between steps",
+    );
+}
+
 /* ─── gif ────────────────────────────────────────────────────────── */
 
 #[test]
@@ -601,6 +2073,7 @@ fn life_renders_at_least_seed_population() {
     cells[1] |= 1; // (8, 0)
     let scene = scene_with(Mode::Life(LifeScene {
         color: Rgb { r: 0, g: 255, b: 128 },
+        fill: None,
         lattice_width: 64,
         lattice_height: 64,
         cells,
@@ -610,6 +2083,181 @@ fn life_renders_at_least_seed_population() {
     assert!(canvas.lit_count() >= 2, "life should light up at least the seeded cells");
 }
 
+/* ─── playlist ───────────────────────────────────────────────────── */
+
+fn playlist_item(mode: Mode, duration_steps: usize) -> PlaylistItem {
+    PlaylistItem {
+        mode,
+        duration_steps,
+        transition: PlaylistTransition::Cut,
+    }
+}
+
+#[test]
+fn empty_playlist_renders_blank() {
+    let scene = scene_with(Mode::Playlist(PlaylistScene::default()));
+    let mut canvas = MockCanvas::new(W, H);
+    render(&scene, 7, &mut canvas).unwrap();
+    assert_eq!(canvas.lit_count(), 0);
+}
+
+#[test]
+fn playlist_cycles_items_by_duration() {
+    // 10 steps of color bars, then 10 of (empty, black) text, then wrap.
+    let scene = scene_with(Mode::Playlist(PlaylistScene {
+        items: vec![
+            playlist_item(Mode::Test(TestScene::default()), 10),
+            playlist_item(Mode::default(), 10),
+        ],
+    }));
+    for (step, lit) in [(0, true), (9, true), (10, false), (19, false), (20, true)] {
+        let mut canvas = MockCanvas::new(W, H);
+        render(&scene, step, &mut canvas).unwrap();
+        assert_eq!(canvas.lit_count() > 0, lit, "step {step}");
+    }
+}
+
+#[test]
+fn playlist_restarts_child_step_per_item() {
+    // Same red→blue gif as `gif_advances_through_frames`, placed after
+    // a 5-step filler. Global step 5 is the gif's step 0 → red; global
+    // step 13 is its step 8 → blue.
+    let red: Vec<u8> = std::iter::repeat([255_u8, 0, 0, 255]).take(16).flatten().collect();
+    let blue: Vec<u8> = std::iter::repeat([0_u8, 0, 255, 255]).take(16).flatten().collect();
+    let gif = Mode::Gif(Arc::new(GifScene {
+        width: 4,
+        height: 4,
+        frames: vec![
+            GifFrame { bitmap: red, delay_ms: 100 },
+            GifFrame { bitmap: blue, delay_ms: 100 },
+        ],
+        speed: 1.0,
+    }));
+    let scene = scene_with(Mode::Playlist(PlaylistScene {
+        items: vec![playlist_item(Mode::default(), 5), playlist_item(gif, 20)],
+    }));
+
+    let mut canvas = MockCanvas::new(W, H);
+    render(&scene, 5, &mut canvas).unwrap();
+    let centre = canvas.at(W / 2, H / 2);
+    assert!(centre.r() > 0 && centre.b() == 0, "item start should be the gif's first frame");
+
+    let mut canvas = MockCanvas::new(W, H);
+    render(&scene, 13, &mut canvas).unwrap();
+    let centre = canvas.at(W / 2, H / 2);
+    assert!(centre.b() > 0 && centre.r() == 0, "8 steps in should be the second frame");
+}
+
+#[test]
+fn playlist_fade_dims_item_edges() {
+    let scene = scene_with(Mode::Playlist(PlaylistScene {
+        items: vec![PlaylistItem {
+            mode: Mode::Test(TestScene::default()),
+            duration_steps: 20,
+            transition: PlaylistTransition::Fade { steps: 4 },
+        }],
+    }));
+    let sum_at = |step| {
+        let mut canvas = MockCanvas::new(W, H);
+        render(&scene, step, &mut canvas).unwrap();
+        channel_sum(&canvas)
+    };
+    let (start, middle, end) = (sum_at(0), sum_at(10), sum_at(19));
+    assert!(start < middle, "fade-in: {start} should be dimmer than {middle}");
+    assert!(end < middle, "fade-out: {end} should be dimmer than {middle}");
+    assert!(start > 0, "fade starts above black");
+}
+
+#[test]
+fn nested_playlist_renders_blank() {
+    let inner = Mode::Playlist(PlaylistScene {
+        items: vec![playlist_item(Mode::Test(TestScene::default()), 10)],
+    });
+    let scene = scene_with(Mode::Playlist(PlaylistScene {
+        items: vec![playlist_item(inner, 10)],
+    }));
+    let mut canvas = MockCanvas::new(W, H);
+    render(&scene, 0, &mut canvas).unwrap();
+    assert_eq!(canvas.lit_count(), 0);
+}
+
+/* ─── transitions ────────────────────────────────────────────────── */
+
+/// Transition from a checkerboard (half the pixels white) to a black
+/// canvas, rendered `elapsed` frames in.
+fn render_transition(kind: TransitionKind, elapsed: usize) -> MockCanvas {
+    let from = scene_with(Mode::Test(TestScene {
+        pattern: TestPattern::Checkerboard,
+    }));
+    let to = scene_with(Mode::default());
+    let spec = Transition { kind, steps: 10 };
+    let mut canvas = MockCanvas::new(W, H);
+    transition::render(&spec, elapsed, &from, 0, &to, 0, &mut canvas).unwrap();
+    canvas
+}
+
+#[test]
+fn transition_starts_on_old_scene_and_ends_on_new() {
+    for kind in [
+        TransitionKind::Crossfade,
+        TransitionKind::WipeHorizontal,
+        TransitionKind::WipeVertical,
+        TransitionKind::SlideHorizontal,
+        TransitionKind::SlideVertical,
+        TransitionKind::Dissolve,
+    ] {
+        let start = render_transition(kind, 0);
+        assert_eq!(start.lit_count(), (W * H / 2) as usize, "{kind:?} at 0");
+        let end = render_transition(kind, 10);
+        assert_eq!(end.lit_count(), 0, "{kind:?} at the end");
+    }
+}
+
+#[test]
+fn cut_transition_renders_new_scene_immediately() {
+    assert_eq!(render_transition(TransitionKind::Cut, 0).lit_count(), 0);
+}
+
+#[test]
+fn transition_defaults_to_a_cut() {
+    let empty: Transition = serde_json::from_str("{}").unwrap();
+    assert_eq!(empty, Transition::default());
+    assert_eq!(empty.kind, TransitionKind::Cut);
+    assert!(empty.is_done(0));
+    let picked: Transition = serde_json::from_str(r#"{"kind": "Crossfade"}"#).unwrap();
+    assert_eq!(picked.steps, 30);
+    assert!(!picked.is_done(0));
+}
+
+#[test]
+fn crossfade_midpoint_is_half_intensity() {
+    let mid = render_transition(TransitionKind::Crossfade, 5);
+    let lit = mid.at(0, 0);
+    assert!(lit.r() > 100 && lit.r() < 155, "expected ~half white, got {lit:?}");
+}
+
+#[test]
+fn wipe_horizontal_reveals_from_the_left() {
+    let mid = render_transition(TransitionKind::WipeHorizontal, 5);
+    let lit_in = |x0: u32, x1: u32| {
+        (x0..x1)
+            .flat_map(|x| (0..H).map(move |y| (x, y)))
+            .filter(|&(x, y)| mid.at(x, y) != Rgb888::BLACK)
+            .count()
+    };
+    assert_eq!(lit_in(0, W / 2), 0, "left half already shows the new (black) scene");
+    assert!(lit_in(W / 2, W) > 0, "right half still shows the old scene");
+}
+
+#[test]
+fn dissolve_flips_more_pixels_as_it_progresses() {
+    let counts: Vec<usize> = (0..=10)
+        .map(|elapsed| render_transition(TransitionKind::Dissolve, elapsed).lit_count())
+        .collect();
+    assert!(counts.windows(2).all(|w| w[1] <= w[0]), "{counts:?}");
+    assert!(counts[5] > 0 && counts[5] < counts[0], "{counts:?}");
+}
+
 /* ─── test patterns ──────────────────────────────────────────────── */
 
 #[test]
@@ -0,0 +1,34 @@
+//! File sensor: the reading is a number in a file, re-read every tick.
+//! Covers sensors the kernel already drives — an IIO light sensor's
+//! `in_illuminance_input` — and simulating one by writing lux into a
+//! plain file.
+
+use std::path::PathBuf;
+
+use anyhow::Context;
+
+use super::LightSensor;
+
+pub struct FileSensor {
+    path: PathBuf,
+    scale: f32,
+}
+
+impl FileSensor {
+    #[must_use]
+    pub fn new(path: PathBuf, scale: f32) -> Self {
+        Self { path, scale }
+    }
+}
+
+impl LightSensor for FileSensor {
+    fn lux(&mut self) -> anyhow::Result<f32> {
+        let raw = std::fs::read_to_string(&self.path)
+            .with_context(|| format!("read {}", self.path.display()))?;
+        let value: f32 = raw
+            .trim()
+            .parse()
+            .with_context(|| format!("parse {}: {:?}", self.path.display(), raw.trim()))?;
+        Ok(value * self.scale)
+    }
+}
@@ -0,0 +1,152 @@
+//! I2C lux sensors on a Linux i2c-dev bus (`/dev/i2c-1` on a Pi;
+//! enable it with `dtparam=i2c_arm=on`). Each opens the bus, claims
+//! its address and talks to the chip with plain reads and writes.
+
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::os::fd::AsRawFd;
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+use anyhow::Context;
+
+use super::LightSensor;
+
+/// i2c-dev ioctl binding the open file to one device address.
+const I2C_SLAVE: u16 = 0x0703;
+
+/// One device on an i2c-dev bus.
+struct I2cDevice {
+    file: File,
+}
+
+impl I2cDevice {
+    fn open(bus: &Path, address: u16) -> anyhow::Result<Self> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(bus)
+            .with_context(|| format!("open {}", bus.display()))?;
+        // SAFETY: I2C_SLAVE takes the address by value and touches no
+        // memory of ours; the fd is open for the duration of the call.
+        let rc = unsafe {
+            libc::ioctl(
+                file.as_raw_fd(),
+                I2C_SLAVE.into(),
+                libc::c_ulong::from(address),
+            )
+        };
+        if rc < 0 {
+            return Err(io::Error::last_os_error())
+                .with_context(|| format!("claim I2C address {address:#04x} on {}", bus.display()));
+        }
+        Ok(Self { file })
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.file.write_all(bytes)
+    }
+
+    fn read<const N: usize>(&mut self) -> io::Result<[u8; N]> {
+        let mut buf = [0; N];
+        self.file.read_exact(&mut buf)?;
+        Ok(buf)
+    }
+}
+
+pub(super) fn default_bh1750_address() -> u16 {
+    0x23
+}
+
+pub(super) fn default_tsl2561_address() -> u16 {
+    0x39
+}
+
+/// ROHM BH1750: one 16-bit count, 1.2 counts per lux.
+pub struct Bh1750 {
+    device: I2cDevice,
+}
+
+impl Bh1750 {
+    const POWER_ON: u8 = 0x01;
+    /// Continuous 1 lx resolution mode, a new reading every ~120 ms.
+    const CONTINUOUS_HIGH_RES: u8 = 0x10;
+
+    pub fn open(bus: &Path, address: u16) -> anyhow::Result<Self> {
+        let mut device = I2cDevice::open(bus, address)?;
+        device.write(&[Self::POWER_ON])?;
+        device.write(&[Self::CONTINUOUS_HIGH_RES])?;
+        // Let the first measurement land before the first read.
+        thread::sleep(Duration::from_millis(180));
+        Ok(Self { device })
+    }
+}
+
+impl LightSensor for Bh1750 {
+    fn lux(&mut self) -> anyhow::Result<f32> {
+        let raw = self.device.read::<2>().context("read BH1750")?;
+        Ok(f32::from(u16::from_be_bytes(raw)) / 1.2)
+    }
+}
+
+/// AMS TSL2561: a broadband and an infrared channel, combined into lux
+/// with the datasheet's piecewise fit.
+pub struct Tsl2561 {
+    device: I2cDevice,
+}
+
+impl Tsl2561 {
+    /// Command bit, set on every register access.
+    const COMMAND: u8 = 0x80;
+    /// Word bit: read the register and the next as one 16-bit value.
+    const WORD: u8 = 0x20;
+    const CONTROL: u8 = 0x00;
+    const TIMING: u8 = 0x01;
+    const DATA0: u8 = 0x0C;
+    const DATA1: u8 = 0x0E;
+    const POWER_ON: u8 = 0x03;
+    /// 402 ms integration at 1× gain, so direct sunlight doesn't
+    /// saturate.
+    const INTEGRATE_402MS: u8 = 0x02;
+    /// The datasheet's fit assumes 16× gain.
+    const GAIN_SCALE: f32 = 16.0;
+
+    pub fn open(bus: &Path, address: u16) -> anyhow::Result<Self> {
+        let mut device = I2cDevice::open(bus, address)?;
+        device.write(&[Self::COMMAND | Self::CONTROL, Self::POWER_ON])?;
+        device.write(&[Self::COMMAND | Self::TIMING, Self::INTEGRATE_402MS])?;
+        thread::sleep(Duration::from_millis(410));
+        Ok(Self { device })
+    }
+
+    fn channel(&mut self, register: u8) -> io::Result<f32> {
+        self.device
+            .write(&[Self::COMMAND | Self::WORD | register])?;
+        let raw = self.device.read::<2>()?;
+        Ok(f32::from(u16::from_le_bytes(raw)) * Self::GAIN_SCALE)
+    }
+}
+
+impl LightSensor for Tsl2561 {
+    fn lux(&mut self) -> anyhow::Result<f32> {
+        let broadband = self.channel(Self::DATA0).context("read TSL2561")?;
+        let infrared = self.channel(Self::DATA1).context("read TSL2561")?;
+        if broadband <= 0.0 {
+            return Ok(0.0);
+        }
+        let ratio = infrared / broadband;
+        let lux = if ratio <= 0.5 {
+            0.0304 * broadband - 0.062 * broadband * ratio.powf(1.4)
+        } else if ratio <= 0.61 {
+            0.0224 * broadband - 0.031 * infrared
+        } else if ratio <= 0.80 {
+            0.0128 * broadband - 0.0153 * infrared
+        } else if ratio <= 1.30 {
+            0.00146 * broadband - 0.00112 * infrared
+        } else {
+            0.0
+        };
+        Ok(lux.max(0.0))
+    }
+}
@@ -0,0 +1,316 @@
+//! Ambient-light auto-brightness.
+//!
+//! [`run`] reads a [`LightSensor`] on an interval, smooths the lux
+//! reading, maps it through the configured curve and stores the result
+//! in [`DataValues`], where the render loop multiplies it into the
+//! panel's brightness — the dash's brightness becomes the ceiling the
+//! room dims from. A schedule window's brightness still wins outright.
+//! Which sensor is read is picked by `[ambient_light]` in
+//! `config.toml`:
+//!
+//! * [`Bh1750`] / [`Tsl2561`] — I2C lux sensors on a Linux i2c-dev bus.
+//! * [`FileSensor`] — a number in a file: a sysfs/IIO illuminance
+//!   attribute, or a hand-edited file to simulate one.
+//!
+//! A sensor that can't be read keeps the last brightness on the panel
+//! and is reopened on the next tick, so unplugging one never takes the
+//! panel down.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::data::DataValues;
+
+pub mod file;
+pub mod i2c;
+
+pub use file::FileSensor;
+pub use i2c::{Bh1750, Tsl2561};
+
+/// A source of ambient light readings. Reads block (bus transfers,
+/// file reads), so [`run`] calls them off the async executor.
+pub trait LightSensor: Send {
+    /// Current illuminance in lux. One attempt; [`run`] owns retry.
+    fn lux(&mut self) -> anyhow::Result<f32>;
+}
+
+/// Auto-brightness settings, from an `[ambient_light]` table:
+///
+/// ```toml
+/// [ambient_light]
+/// sensor = { kind = "bh1750", bus = "/dev/i2c-1" }
+/// curve = [[0, 0.05], [50, 0.3], [1000, 1.0]]
+/// ```
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct AmbientConfig {
+    pub sensor: SensorConfig,
+    /// `[lux, brightness]` points, interpolated on a log lux scale
+    /// (eyes judge light by ratios) and held flat past either end.
+    #[serde(default = "default_curve")]
+    pub curve: Vec<[f32; 2]>,
+    /// Time constant of the smoothing, so a passing shadow or a car's
+    /// headlights don't pump the panel. 0 = follow every reading.
+    #[serde(default = "default_smoothing_secs")]
+    pub smoothing_secs: f32,
+    /// Smallest brightness change worth showing; keeps the panel from
+    /// hunting between two levels at dusk.
+    #[serde(default = "default_hysteresis")]
+    pub hysteresis: f32,
+    #[serde(default = "default_interval_ms")]
+    pub interval_ms: u64,
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum SensorConfig {
+    Bh1750 {
+        #[serde(default = "default_bus")]
+        bus: PathBuf,
+        #[serde(default = "i2c::default_bh1750_address")]
+        address: u16,
+    },
+    Tsl2561 {
+        #[serde(default = "default_bus")]
+        bus: PathBuf,
+        #[serde(default = "i2c::default_tsl2561_address")]
+        address: u16,
+    },
+    /// A file holding a single number, re-read every tick, e.g.
+    /// `/sys/bus/iio/devices/iio:device0/in_illuminance_input`.
+    File {
+        path: PathBuf,
+        /// Multiplier taking the file's number to lux, for raw IIO
+        /// channels (their `_scale` attribute).
+        #[serde(default = "default_scale")]
+        scale: f32,
+    },
+}
+
+impl SensorConfig {
+    fn open(&self) -> anyhow::Result<Box<dyn LightSensor>> {
+        Ok(match self {
+            Self::Bh1750 { bus, address } => Box::new(Bh1750::open(bus, *address)?),
+            Self::Tsl2561 { bus, address } => Box::new(Tsl2561::open(bus, *address)?),
+            Self::File { path, scale } => Box::new(FileSensor::new(path.clone(), *scale)),
+        })
+    }
+}
+
+/// Dim indoors at night, full in daylight.
+fn default_curve() -> Vec<[f32; 2]> {
+    vec![[0.0, 0.05], [10.0, 0.15], [200.0, 0.5], [2000.0, 1.0]]
+}
+
+fn default_smoothing_secs() -> f32 {
+    10.0
+}
+
+fn default_hysteresis() -> f32 {
+    0.05
+}
+
+fn default_interval_ms() -> u64 {
+    1000
+}
+
+/// The Pi's GPIO-header bus.
+fn default_bus() -> PathBuf {
+    PathBuf::from("/dev/i2c-1")
+}
+
+fn default_scale() -> f32 {
+    1.0
+}
+
+impl AmbientConfig {
+    /// Brightness for `lux`, per [`Self::curve`]. Full with no curve.
+    #[must_use]
+    pub fn brightness(&self, lux: f32) -> f32 {
+        self.curve_at(log_lux(lux))
+    }
+
+    /// The curve at `x` on the [`log_lux`] scale.
+    fn curve_at(&self, x: f32) -> f32 {
+        let mut points = self.curve.iter().map(|&[lux, b]| (log_lux(lux), b));
+        let Some(mut lower) = points.next() else {
+            return 1.0;
+        };
+        if x <= lower.0 {
+            return lower.1.clamp(0.0, 1.0);
+        }
+        for upper in points {
+            if x <= upper.0 {
+                let t = (x - lower.0) / (upper.0 - lower.0).max(f32::EPSILON);
+                return (lower.1 + t * (upper.1 - lower.1)).clamp(0.0, 1.0);
+            }
+            lower = upper;
+        }
+        lower.1.clamp(0.0, 1.0)
+    }
+}
+
+/// Lux on a log scale, offset so darkness (0 lux) is finite.
+fn log_lux(lux: f32) -> f32 {
+    (lux.max(0.0) + 1.0).log10()
+}
+
+/// Smoothing and hysteresis between raw readings and the brightness
+/// shown.
+struct Filter {
+    /// Exponential moving average of [`log_lux`].
+    smoothed: Option<f32>,
+    shown: Option<f32>,
+}
+
+impl Filter {
+    /// Fold in a reading taken `dt` after the last; the brightness to
+    /// show if it moved by at least the hysteresis.
+    fn update(&mut self, config: &AmbientConfig, lux: f32, dt: Duration) -> Option<f32> {
+        let x = log_lux(lux);
+        let smoothed = match self.smoothed {
+            Some(previous) if config.smoothing_secs > 0.0 => {
+                let alpha = 1.0 - (-dt.as_secs_f32() / config.smoothing_secs).exp();
+                previous + alpha * (x - previous)
+            }
+            _ => x,
+        };
+        self.smoothed = Some(smoothed);
+        let target = config.curve_at(smoothed);
+        match self.shown {
+            Some(shown) if (target - shown).abs() < config.hysteresis => None,
+            _ => {
+                self.shown = Some(target);
+                Some(target)
+            }
+        }
+    }
+}
+
+/// Read the configured sensor every `interval_ms` forever, storing
+/// the brightness it maps to in `values`.
+pub async fn run(config: AmbientConfig, values: DataValues) -> anyhow::Result<()> {
+    let interval = Duration::from_millis(config.interval_ms.max(100));
+    let mut tick = tokio::time::interval(interval);
+    let mut sensor: Option<Box<dyn LightSensor>> = None;
+    let mut filter = Filter {
+        smoothed: None,
+        shown: None,
+    };
+    let mut failing = false;
+    loop {
+        tick.tick().await;
+        // A sensor only goes back once it has read cleanly, so a
+        // failure reopens it next tick.
+        let reading = tokio::task::block_in_place(|| -> anyhow::Result<f32> {
+            let mut current = match sensor.take() {
+                Some(current) => current,
+                None => config.sensor.open()?,
+            };
+            let lux = current.lux()?;
+            sensor = Some(current);
+            Ok(lux)
+        });
+        match reading {
+            Ok(lux) => {
+                failing = false;
+                if let Some(brightness) = filter.update(&config, lux, interval) {
+                    tracing::debug!(lux, brightness, "ambient brightness changed");
+                    values.write().ambient_brightness = Some(brightness);
+                }
+            }
+            Err(err) => {
+                // Only log the first failure of a run.
+                if !failing {
+                    tracing::warn!(error = ?err, "ambient light read failed; keeping last brightness");
+                }
+                failing = true;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(smoothing_secs: f32, hysteresis: f32) -> AmbientConfig {
+        AmbientConfig {
+            sensor: SensorConfig::File {
+                path: PathBuf::from("/dev/null"),
+                scale: 1.0,
+            },
+            curve: default_curve(),
+            smoothing_secs,
+            hysteresis,
+            interval_ms: 1000,
+        }
+    }
+
+    fn filter() -> Filter {
+        Filter {
+            smoothed: None,
+            shown: None,
+        }
+    }
+
+    const SECOND: Duration = Duration::from_secs(1);
+
+    #[test]
+    fn readings_inside_the_hysteresis_band_change_nothing() {
+        let config = config(0.0, 0.05);
+        let mut filter = filter();
+        let first = filter.update(&config, 200.0, SECOND).unwrap();
+        assert!((first - 0.5).abs() < 1e-4, "{first}");
+        // 190 lx is a hair dimmer on the curve: not worth showing.
+        assert!((config.brightness(190.0) - first).abs() < config.hysteresis);
+        assert_eq!(filter.update(&config, 190.0, SECOND), None);
+        assert_eq!(filter.update(&config, 210.0, SECOND), None);
+        assert_eq!(filter.shown, Some(first));
+        // 20 lx is well outside it.
+        let dimmer = filter.update(&config, 20.0, SECOND).unwrap();
+        assert!(dimmer < first - config.hysteresis);
+    }
+
+    #[test]
+    fn smoothing_converges_on_a_steady_reading() {
+        let config = config(10.0, 0.0);
+        let mut smoother = filter();
+        smoother.update(&config, 0.0, SECOND);
+        let target = config.brightness(2000.0);
+        let mut last = config.brightness(0.0);
+        for _ in 0..100 {
+            let shown = smoother.update(&config, 2000.0, SECOND).unwrap_or(last);
+            assert!(shown >= last && shown <= target, "{last} -> {shown}");
+            last = shown;
+        }
+        assert!((last - target).abs() < 1e-3, "{last} vs {target}");
+        // One reading moves it only part of the way.
+        let mut fresh = filter();
+        fresh.update(&config, 0.0, SECOND);
+        let step = fresh.update(&config, 2000.0, SECOND).unwrap();
+        assert!(
+            step > config.brightness(0.0) && step < target / 2.0,
+            "{step}"
+        );
+    }
+
+    #[test]
+    fn curve_holds_flat_and_clamps_past_its_ends() {
+        let config = config(0.0, 0.0);
+        assert!((config.brightness(0.0) - 0.05).abs() < 1e-6);
+        assert!((config.brightness(-5.0) - 0.05).abs() < 1e-6);
+        assert!((config.brightness(1e6) - 1.0).abs() < 1e-6);
+        let wild = AmbientConfig {
+            curve: vec![[10.0, -0.5], [1000.0, 2.0]],
+            ..config.clone()
+        };
+        assert!(wild.brightness(0.0).abs() < 1e-6);
+        assert!((wild.brightness(1e6) - 1.0).abs() < 1e-6);
+        assert!((0.0..=1.0).contains(&wild.brightness(100.0)));
+        let none = AmbientConfig {
+            curve: Vec::new(),
+            ..config
+        };
+        assert!((none.brightness(50.0) - 1.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,315 @@
+//! Local HTTP control API.
+//!
+//! Optional LAN-side alternative to the Supabase control plane: reads
+//! and writes the same `Arc<RwLock<State>>` the render loop draws
+//! from, so a panel on an isolated network (or a test harness on
+//! localhost) can be driven without the cloud. Enabled by setting
+//! `local_api_addr` in `config.toml`.
+//!
+//! Routes (all bodies are JSON):
+//!
+//! * `GET  /panel` — panel fields (scroll, pause, flash, mode, …).
+//! * `PUT  /panel` — merge the given top-level fields into the panel.
+//! * `GET|PUT /entries` — the full text-entry list.
+//! * `GET|PUT /mode` — `{ "mode": "...", "mode_config": {...} }`.
+//! * `GET|PUT /mode_config` — just the mode-specific config.
+//!
+//! Every write stamps a fresh `last_updated` so the render loop's
+//! config cache re-parses. When Supabase is reachable the next
+//! realtime nudge sees that stamp differ from the row and re-pulls —
+//! the cloud stays authoritative whenever it's there.
+
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::Context;
+use axum::extract::State as AxumState;
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::Router;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+use crate::display::{Panel, TextEntry};
+use crate::state::State;
+
+struct ApiState {
+    state: Arc<RwLock<State>>,
+    /// Where (and under which panel name) to persist local writes so
+    /// they survive a reboot while the cloud is unreachable.
+    cache: Option<(PathBuf, String)>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct ModeBody {
+    mode: String,
+    #[serde(default)]
+    mode_config: JsonValue,
+}
+
+/// Serve the control API on `addr` until the process exits.
+pub async fn serve(
+    addr: SocketAddr,
+    state: Arc<RwLock<State>>,
+    panel_name: String,
+    state_cache_path: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    let api_state = Arc::new(ApiState {
+        state,
+        cache: state_cache_path.map(|path| (path, panel_name)),
+    });
+    let app = router(api_state);
+
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("bind local API on {addr}"))?;
+    tracing::info!(%addr, "Local control API listening");
+    axum::serve(listener, app).await.context("local API serve")?;
+    Ok(())
+}
+
+fn router(api_state: Arc<ApiState>) -> Router {
+    Router::new()
+        .route("/panel", get(get_panel).put(put_panel))
+        .route("/entries", get(get_entries).put(put_entries))
+        .route("/mode", get(get_mode).put(put_mode))
+        .route("/mode_config", get(get_mode_config).put(put_mode_config))
+        .with_state(api_state)
+}
+
+impl ApiState {
+    /// Apply `edit` to the shared state, stamp `last_updated`, and
+    /// persist the result.
+    fn write(&self, edit: impl FnOnce(&mut State)) {
+        self.try_write(|state| -> Result<(), Infallible> {
+            edit(state);
+            Ok(())
+        })
+        .unwrap_or_else(|never| match never {});
+    }
+
+    /// [`Self::write`] for an edit that can fail, all under one lock so
+    /// a sync can't land between reading the state and writing it back.
+    /// A failed edit leaves the state untouched.
+    fn try_write<E>(&self, edit: impl FnOnce(&mut State) -> Result<(), E>) -> Result<(), E> {
+        let mut state = self.state.write();
+        edit(&mut state)?;
+        state.panel.last_updated = format!("local:{}", chrono::Utc::now().to_rfc3339());
+        // Still under the lock, so snapshots reach the cache in the
+        // order they were written.
+        if let Some((path, panel_name)) = &self.cache {
+            crate::cache::persist(path.clone(), panel_name.clone(), state.clone());
+        }
+        Ok(())
+    }
+}
+
+fn json<T: Serialize>(value: &T) -> Response {
+    match serde_json::to_string(value) {
+        Ok(body) => ([(header::CONTENT_TYPE, "application/json")], body).into_response(),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+}
+
+fn bad_request(err: &serde_json::Error) -> Response {
+    (StatusCode::BAD_REQUEST, format!("invalid body: {err}")).into_response()
+}
+
+async fn get_panel(AxumState(api): AxumState<Arc<ApiState>>) -> Response {
+    json(&api.state.read().panel)
+}
+
+async fn put_panel(AxumState(api): AxumState<Arc<ApiState>>, body: String) -> Response {
+    let patch = match serde_json::from_str::<JsonValue>(&body) {
+        Ok(JsonValue::Object(patch)) => patch,
+        Ok(_) => return (StatusCode::BAD_REQUEST, "expected a JSON object").into_response(),
+        Err(err) => return bad_request(&err),
+    };
+    // Merge onto the current panel so callers can send just the
+    // fields they care about (`{"brightness": 0.4}`).
+    let merged = api.try_write(|state| {
+        let Ok(JsonValue::Object(mut merged)) = serde_json::to_value(&state.panel) else {
+            unreachable!("Panel serializes to a JSON object")
+        };
+        merged.extend(patch);
+        let mut panel: Panel = serde_json::from_value(JsonValue::Object(merged))?;
+        // `id` doesn't round-trip through the wire shape;
+        // `last_updated` is restamped by `try_write`.
+        panel.id = std::mem::take(&mut state.panel.id);
+        state.panel = panel;
+        Ok(())
+    });
+    if let Err(err) = merged {
+        return bad_request(&err);
+    }
+    json(&api.state.read().panel)
+}
+
+async fn get_entries(AxumState(api): AxumState<Arc<ApiState>>) -> Response {
+    json(&api.state.read().entries)
+}
+
+async fn put_entries(AxumState(api): AxumState<Arc<ApiState>>, body: String) -> Response {
+    let entries: Vec<TextEntry> = match serde_json::from_str(&body) {
+        Ok(entries) => entries,
+        Err(err) => return bad_request(&err),
+    };
+    api.write(|state| state.entries = entries);
+    json(&api.state.read().entries)
+}
+
+async fn get_mode(AxumState(api): AxumState<Arc<ApiState>>) -> Response {
+    let state = api.state.read();
+    json(&ModeBody {
+        mode: state.panel.mode.clone(),
+        mode_config: state.panel.mode_config.clone(),
+    })
+}
+
+async fn put_mode(AxumState(api): AxumState<Arc<ApiState>>, body: String) -> Response {
+    let ModeBody { mode, mode_config } = match serde_json::from_str(&body) {
+        Ok(body) => body,
+        Err(err) => return bad_request(&err),
+    };
+    api.write(|state| {
+        state.panel.mode = mode;
+        // Mirror the DB's `not null` jsonb default for a missing config.
+        state.panel.mode_config = if mode_config.is_null() {
+            serde_json::json!({})
+        } else {
+            mode_config
+        };
+    });
+    get_mode(AxumState(api)).await
+}
+
+async fn get_mode_config(AxumState(api): AxumState<Arc<ApiState>>) -> Response {
+    json(&api.state.read().panel.mode_config)
+}
+
+async fn put_mode_config(AxumState(api): AxumState<Arc<ApiState>>, body: String) -> Response {
+    let mode_config: JsonValue = match serde_json::from_str(&body) {
+        Ok(config) => config,
+        Err(err) => return bad_request(&err),
+    };
+    api.write(|state| state.panel.mode_config = mode_config);
+    json(&api.state.read().panel.mode_config)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::{Method, Request};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    fn api() -> Arc<ApiState> {
+        let mut state = State::default();
+        state.panel.id = "panel-id".to_owned();
+        state.panel.name = "kitchen".to_owned();
+        state.panel.mode = "text".to_owned();
+        Arc::new(ApiState {
+            state: Arc::new(RwLock::new(state)),
+            cache: None,
+        })
+    }
+
+    async fn call(
+        api: &Arc<ApiState>,
+        method: Method,
+        uri: &str,
+        body: &str,
+    ) -> (StatusCode, JsonValue) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .body(Body::from(body.to_owned()))
+            .unwrap();
+        let response = router(api.clone()).oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body = serde_json::from_slice(&bytes).unwrap_or(JsonValue::Null);
+        (status, body)
+    }
+
+    #[tokio::test]
+    async fn panel_merges_partial_writes() {
+        let api = api();
+        let (status, body) = call(
+            &api,
+            Method::PUT,
+            "/panel",
+            r#"{"brightness": 0.4, "is_paused": true}"#,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["brightness"], 0.4);
+        assert_eq!(body["name"], "kitchen", "fields not sent are kept");
+        let (_, read) = call(&api, Method::GET, "/panel", "").await;
+        assert_eq!(read, body);
+        let state = api.state.read();
+        assert!(state.panel.is_paused);
+        assert_eq!(state.panel.id, "panel-id");
+        assert!(state.panel.last_updated.starts_with("local:"));
+    }
+
+    #[tokio::test]
+    async fn entries_round_trip() {
+        let api = api();
+        let entries = r#"[{"text": "hi", "options": {"color": {"Rgb": {"r": 255, "g": 0, "b": 0}}, "marquee": {"speed": 0}}}]"#;
+        let (status, body) = call(&api, Method::PUT, "/entries", entries).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body[0]["text"], "hi");
+        let (_, read) = call(&api, Method::GET, "/entries", "").await;
+        assert_eq!(read, body);
+        assert_eq!(api.state.read().entries.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn mode_and_mode_config_round_trip() {
+        let api = api();
+        let (status, body) = call(&api, Method::PUT, "/mode", r#"{"mode": "clock"}"#).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            body,
+            serde_json::json!({"mode": "clock", "mode_config": {}})
+        );
+
+        let config = serde_json::json!({"format": "24h"});
+        let (status, body) = call(&api, Method::PUT, "/mode_config", &config.to_string()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, config);
+        let (_, read) = call(&api, Method::GET, "/mode_config", "").await;
+        assert_eq!(read, config);
+        let (_, mode) = call(&api, Method::GET, "/mode", "").await;
+        assert_eq!(
+            mode,
+            serde_json::json!({"mode": "clock", "mode_config": config})
+        );
+    }
+
+    #[tokio::test]
+    async fn bad_bodies_are_rejected_and_change_nothing() {
+        let api = api();
+        let before = api.state.read().clone();
+        for (uri, body) in [
+            ("/panel", "not json"),
+            ("/panel", "[1, 2]"),
+            ("/panel", r#"{"brightness": "high"}"#),
+            ("/entries", r#"{"text": "hi"}"#),
+            ("/mode", r#"{"mode_config": {}}"#),
+            ("/mode_config", "{"),
+        ] {
+            let (status, _) = call(&api, Method::PUT, uri, body).await;
+            assert_eq!(status, StatusCode::BAD_REQUEST, "PUT {uri} {body}");
+        }
+        assert_eq!(*api.state.read(), before);
+    }
+}
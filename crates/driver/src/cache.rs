@@ -0,0 +1,176 @@
+//! On-disk snapshot of the last successfully downloaded [`State`].
+//!
+//! Written after every pull that changed something, read once at
+//! startup. Lets a Pi that reboots without network render the last
+//! configured scene straight away instead of sitting on the boot
+//! frame until Supabase is reachable again; the sync loop reconciles
+//! against the cloud as soon as it can.
+//!
+//! Writes are atomic (temp file + fsync + rename) so a power cut
+//! mid-write leaves the previous snapshot intact rather than a
+//! truncated file. They're also serialized: one at a time across the
+//! temp file and rename, newest snapshot last.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use anyhow::Context;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+
+use crate::state::State;
+
+/// Wire shape of the cache file. `Panel` skips `id` / `last_updated`
+/// on serialize (they must never be written back to the panels
+/// row), so they're carried alongside the state and patched in on
+/// load.
+#[derive(Deserialize, Serialize)]
+struct Snapshot {
+    /// Config `id` the snapshot was taken under. A Pi re-provisioned
+    /// as a different panel must not resurrect the old one's scene.
+    panel_name: String,
+    panel_id: String,
+    last_updated: String,
+    state: State,
+}
+
+/// Load the cached state for `panel_name`. `None` when there's no
+/// cache yet, it's unreadable, or it belongs to another panel — every
+/// one of those just means "boot like we used to".
+#[must_use]
+pub fn load(path: &Path, panel_name: &str) -> Option<State> {
+    let raw = match fs::read_to_string(path) {
+        Ok(raw) => raw,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return None,
+        Err(err) => {
+            tracing::warn!(error = %err, path = %path.display(), "couldn't read state cache");
+            return None;
+        }
+    };
+    let snapshot: Snapshot = match serde_json::from_str(&raw) {
+        Ok(snapshot) => snapshot,
+        Err(err) => {
+            tracing::warn!(error = %err, path = %path.display(), "state cache unparseable, ignoring");
+            return None;
+        }
+    };
+    if snapshot.panel_name != panel_name {
+        tracing::info!(
+            cached = %snapshot.panel_name,
+            configured = %panel_name,
+            "state cache is for another panel, ignoring"
+        );
+        return None;
+    }
+    let mut state = snapshot.state;
+    state.panel.id = snapshot.panel_id;
+    state.panel.last_updated = snapshot.last_updated;
+    Some(state)
+}
+
+/// Atomically replace the cache at `path` with `state`.
+pub fn store(path: &Path, panel_name: &str, state: &State) -> anyhow::Result<()> {
+    let snapshot = Snapshot {
+        panel_name: panel_name.to_owned(),
+        panel_id: state.panel.id.clone(),
+        last_updated: state.panel.last_updated.clone(),
+        state: state.clone(),
+    };
+    let body = serde_json::to_vec(&snapshot)?;
+
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("create state cache dir {}", dir.display()))?;
+    }
+    let tmp = path.with_extension("tmp");
+    {
+        let mut file = fs::File::create(&tmp)
+            .with_context(|| format!("create {}", tmp.display()))?;
+        file.write_all(&body)?;
+        file.sync_all()?;
+    }
+    fs::rename(&tmp, path)
+        .with_context(|| format!("rename {} -> {}", tmp.display(), path.display()))?;
+    Ok(())
+}
+
+/// Order of [`persist`] calls; a snapshot's number says how new it is.
+static NEXT_SNAPSHOT: AtomicU64 = AtomicU64::new(1);
+
+/// Held across each [`store`] so writes can't interleave on the temp
+/// file; holds the number of the newest snapshot written.
+static WRITTEN: Mutex<u64> = Mutex::new(0);
+
+/// [`store`] off the async runtime. Best effort — a failed write only
+/// costs us the offline resume on the next boot, so it's logged and
+/// otherwise ignored. Writes queued by the sync loop and the local API
+/// land one at a time, and one that's overtaken by a newer snapshot
+/// is dropped instead of replacing it. Callers hold the state's write
+/// lock across the call, so "newer" means newer in memory too.
+pub fn persist(path: PathBuf, panel_name: String, state: State) {
+    let number = NEXT_SNAPSHOT.fetch_add(1, Ordering::Relaxed);
+    tokio::task::spawn_blocking(move || {
+        let mut written = WRITTEN.lock();
+        if *written > number {
+            return;
+        }
+        match store(&path, &panel_name, &state) {
+            Ok(()) => *written = number,
+            Err(err) => tracing::warn!(error = %err, "couldn't write state cache"),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fresh cache path under the system temp dir.
+    fn cache_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("led-cache-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("state.json")
+    }
+
+    fn sample() -> State {
+        let mut state = State::default();
+        state.panel.id = "panel-id".to_owned();
+        state.panel.name = "kitchen".to_owned();
+        state.panel.last_updated = "2026-06-01T12:00:00Z".to_owned();
+        state.panel.mode = "clock".to_owned();
+        state.panel.mode_config = serde_json::json!({"format": "24h"});
+        state.panel.brightness = 0.4;
+        state
+    }
+
+    #[test]
+    fn round_trips_id_and_last_updated() {
+        let path = cache_path("round-trip");
+        assert_eq!(load(&path, "kitchen"), None);
+        store(&path, "kitchen", &sample()).unwrap();
+        assert_eq!(load(&path, "kitchen"), Some(sample()));
+        assert!(!path.with_extension("tmp").exists());
+    }
+
+    #[test]
+    fn ignores_another_panels_cache() {
+        let path = cache_path("other-panel");
+        store(&path, "kitchen", &sample()).unwrap();
+        assert_eq!(load(&path, "hallway"), None);
+    }
+
+    #[test]
+    fn ignores_a_truncated_or_corrupt_file() {
+        let path = cache_path("corrupt");
+        store(&path, "kitchen", &sample()).unwrap();
+        let body = fs::read(&path).unwrap();
+        fs::write(&path, &body[..body.len() / 2]).unwrap();
+        assert_eq!(load(&path, "kitchen"), None);
+        fs::write(&path, "not json").unwrap();
+        assert_eq!(load(&path, "kitchen"), None);
+        fs::write(&path, "").unwrap();
+        assert_eq!(load(&path, "kitchen"), None);
+    }
+}
@@ -1,9 +1,16 @@
 //! Driver configuration loaded from a TOML file at runtime.
 
 use std::fs;
+use std::net::SocketAddr;
 use std::path::{Path, PathBuf};
+use display_core::calibration::Calibration;
 use thiserror::Error;
 
+use crate::ambient::AmbientConfig;
+use crate::data::DataSourceConfig;
+use crate::geometry::MatrixConfig;
+use crate::weather::WeatherConfig;
+
 /// Errors that can occur loading the driver config.
 #[derive(Error, Debug)]
 pub enum Error {
@@ -13,6 +20,9 @@ pub enum Error {
     /// Failed to deserialize the configuration as TOML.
     #[error("config parse: {0}")]
     Parse(#[from] toml::de::Error),
+    /// Parsed, but the combination of settings can't work.
+    #[error("config invalid: {0}")]
+    Invalid(String),
 }
 
 /// Driver runtime configuration.
@@ -24,10 +34,19 @@ pub struct Config {
     /// Directory for rolling log files.
     pub log_dir: PathBuf,
 
+    /// Where panel state comes from. Defaults to Supabase, configured
+    /// by the two fields below.
+    #[serde(default)]
+    pub source: SourceConfig,
+
     /// Supabase PostgREST endpoint, e.g. `https://<project>.supabase.co/rest/v1`.
+    /// Required when `source.kind = "supabase"`.
+    #[serde(default)]
     pub supabase_url: String,
 
     /// Supabase API key (anon role) sent as the `apikey` header.
+    /// Required when `source.kind = "supabase"`.
+    #[serde(default)]
     pub supabase_anon_key: String,
 
     /// OTLP/HTTP endpoint for telemetry export, e.g. `http://infra:4318`.
@@ -52,15 +71,171 @@ pub struct Config {
     /// Case-insensitive. Unset = `RGB`.
     #[serde(default)]
     pub color_order: Option<String>,
+
+    /// Panel size, chaining and pixel mapping, as a `[matrix]` table;
+    /// see [`crate::geometry`]. Every renderer draws on the virtual
+    /// canvas this describes.
+    ///
+    /// If absent, a single 64×64 panel.
+    #[serde(default)]
+    pub matrix: MatrixConfig,
+
+    /// Color correction for this Pi's panel, as a `[calibration]`
+    /// table; see [`display_core::calibration`]. The panel row's
+    /// `calibration` overrides it key by key.
+    ///
+    /// If absent, colors go out as rendered.
+    #[serde(default)]
+    pub calibration: Calibration,
+
+    /// Where to persist the last successfully synced panel state, e.g.
+    /// `/var/lib/led/state.json`. Loaded at startup so the panel
+    /// resumes its last scene even when the network is down at boot.
+    ///
+    /// If absent or empty, the cache is disabled and the panel shows
+    /// the boot frame until the first pull succeeds.
+    #[serde(default)]
+    pub state_cache_path: Option<PathBuf>,
+
+    /// Address to serve the local HTTP control API on, e.g.
+    /// `0.0.0.0:8080`. Lets the panel be driven over the LAN when the
+    /// Supabase project is unreachable; see [`crate::api`].
+    ///
+    /// If absent, the API is disabled. Unauthenticated — only enable
+    /// it on networks you trust.
+    #[serde(default)]
+    pub local_api_addr: Option<SocketAddr>,
+
+    /// Directory of `.bdf` or `.pcf` bitmap fonts, e.g.
+    /// `/usr/local/share/led/fonts`. Each is loaded at startup and selectable by its file stem (a text
+    /// entry with `"font": {"Bdf": "spleen-8x16"}` uses
+    /// `spleen-8x16.bdf`).
+    ///
+    /// If absent, only the built-in fonts are available.
+    #[serde(default)]
+    pub font_dir: Option<PathBuf>,
+
+    /// Fonts from `font_dir`, by file stem, to draw characters the
+    /// built-in fonts don't cover — e.g. `["unifont"]` for CJK. Tried
+    /// in order after the built-in Latin, Cyrillic, Greek and symbol
+    /// glyphs.
+    #[serde(default)]
+    pub fallback_fonts: Vec<String>,
+
+    /// HTTP endpoints polled for ticker mode, one `[[data_sources]]`
+    /// table each; see [`crate::data`].
+    ///
+    /// If empty, ticker mode shows its entries' placeholders as-is.
+    #[serde(default)]
+    pub data_sources: Vec<DataSourceConfig>,
+
+    /// Where weather mode's current conditions come from, as a
+    /// `[weather]` table; see [`crate::weather`].
+    ///
+    /// If absent, weather mode shows a placeholder temperature.
+    #[serde(default)]
+    pub weather: Option<WeatherConfig>,
+
+    /// Ambient light sensor driving auto-brightness, as an
+    /// `[ambient_light]` table; see [`crate::ambient`].
+    ///
+    /// If absent, brightness is whatever the dash sets.
+    #[serde(default)]
+    pub ambient_light: Option<AmbientConfig>,
+}
+
+/// Backend the sync loop pulls panel state from; see
+/// [`crate::source`]. Selected by a `[source]` table:
+///
+/// ```toml
+/// [source]
+/// kind = "file"
+/// path = "/etc/led/state.json"
+/// ```
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum SourceConfig {
+    /// Supabase REST + Realtime, using `supabase_url` /
+    /// `supabase_anon_key`.
+    #[default]
+    Supabase,
+    /// A JSON state file, reloaded whenever it changes on disk.
+    File { path: PathBuf },
+    /// Full-state snapshots pushed over a WebSocket (`ws://` / `wss://`).
+    Websocket { url: String },
 }
 
 /// Load configuration from a TOML file.
 ///
 /// # Errors
-/// Returns [`Error::Io`] if the file can't be read, or [`Error::Parse`] if it
-/// isn't valid TOML / doesn't match the [`Config`] schema.
+/// Returns [`Error::Io`] if the file can't be read, [`Error::Parse`] if it
+/// isn't valid TOML / doesn't match the [`Config`] schema, or
+/// [`Error::Invalid`] if the selected source is missing its settings or
+/// the `[matrix]` mappers can't fold its chain.
 pub fn load(path: &Path) -> Result<Config, Error> {
     tracing::debug!(path = %path.display(), "Loading configuration");
     let raw = fs::read_to_string(path)?;
-    Ok(toml::from_str(&raw)?)
+    let config: Config = toml::from_str(&raw)?;
+    if matches!(config.source, SourceConfig::Supabase)
+        && (config.supabase_url.is_empty() || config.supabase_anon_key.is_empty())
+    {
+        return Err(Error::Invalid(
+            "supabase source needs supabase_url and supabase_anon_key".to_owned(),
+        ));
+    }
+    config.matrix.validate().map_err(Error::Invalid)?;
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(extra: &str) -> Result<Config, toml::de::Error> {
+        toml::from_str(&format!(
+            "id = \"kitchen\"\nlog_dir = \"/var/log/led\"\n{extra}"
+        ))
+    }
+
+    #[test]
+    fn source_defaults_to_supabase() {
+        let config = parse("supabase_url = \"https://x.supabase.co\"").unwrap();
+        assert!(matches!(config.source, SourceConfig::Supabase));
+        let explicit = parse("[source]\nkind = \"supabase\"").unwrap();
+        assert!(matches!(explicit.source, SourceConfig::Supabase));
+    }
+
+    #[test]
+    fn source_table_picks_the_backend() {
+        let file = parse("[source]\nkind = \"file\"\npath = \"/etc/led/state.json\"").unwrap();
+        assert!(
+            matches!(&file.source, SourceConfig::File { path } if path == Path::new("/etc/led/state.json"))
+        );
+        let ws = parse("[source]\nkind = \"websocket\"\nurl = \"ws://hub.local/panel\"").unwrap();
+        assert!(
+            matches!(&ws.source, SourceConfig::Websocket { url } if url == "ws://hub.local/panel")
+        );
+    }
+
+    #[test]
+    fn source_table_rejects_unknown_or_incomplete_backends() {
+        assert!(parse("[source]\nkind = \"carrier-pigeon\"").is_err());
+        assert!(parse("[source]\nkind = \"file\"").is_err());
+        assert!(parse("[source]\nkind = \"websocket\"").is_err());
+    }
+
+    #[test]
+    fn supabase_source_needs_its_credentials() {
+        let dir = std::env::temp_dir().join(format!("led-config-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+        std::fs::write(&path, "id = \"kitchen\"\nlog_dir = \"/tmp\"\n").unwrap();
+        assert!(matches!(load(&path), Err(Error::Invalid(_))));
+        std::fs::write(
+            &path,
+            "id = \"kitchen\"\nlog_dir = \"/tmp\"\n[source]\nkind = \"file\"\npath = \"state.json\"\n",
+        )
+        .unwrap();
+        assert!(load(&path).is_ok());
+    }
 }
@@ -0,0 +1,267 @@
+//! Live data for ticker, chart, gauge and weather modes, and the
+//! ambient light level.
+//!
+//! Polls the HTTP endpoints listed under `[[data_sources]]` in
+//! `config.toml` and keeps the latest value of each, plus a short
+//! history of the numeric ones, in the shared [`DataValues`]. Ticker
+//! mode is text mode with every `{name}` in an entry replaced by that
+//! source's value (see [`substitute`]), so `BTC {price}` reads
+//! `BTC 67012.5` once the first poll lands. A chart with a `source`
+//! plots that source's history, and a gauge shows its latest value.
+//!
+//! A source is read as plain text, or as JSON with a small
+//! `JSONPath`-like `selector` picking one value out (see [`select`]).
+//! A failed poll keeps the previous value on the panel; the endpoint
+//! can be anything reachable over HTTP, a local stand-in included.
+
+use std::collections::{HashMap, VecDeque};
+use std::hash::BuildHasher;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Context;
+use display_core::weather::WeatherReport;
+use parking_lot::RwLock;
+use serde_json::Value as JsonValue;
+use tokio::task::JoinSet;
+
+/// What the pollers have seen so far, shared with the render loop.
+pub type DataValues = Arc<RwLock<SourceValues>>;
+
+/// Samples kept per source — enough for a chart across a full-width
+/// chain of panels.
+const HISTORY_LEN: usize = 256;
+
+#[derive(Debug, Default)]
+pub struct SourceValues {
+    /// Latest value of each source, by name.
+    pub latest: HashMap<String, String>,
+    /// Recent values of each source that parse as a number, oldest
+    /// first.
+    pub history: HashMap<String, VecDeque<f32>>,
+    /// Latest current conditions from the `[weather]` provider; see
+    /// [`crate::weather`].
+    pub weather: Option<WeatherReport>,
+    /// Brightness the ambient light sensor calls for, in [0, 1]; see
+    /// [`crate::ambient`]. `None` until its first reading.
+    pub ambient_brightness: Option<f32>,
+}
+
+impl SourceValues {
+    fn record(&mut self, name: &str, value: String) {
+        if let Ok(number) = value.parse::<f32>() {
+            let history = self.history.entry(name.to_owned()).or_default();
+            if history.len() == HISTORY_LEN {
+                history.pop_front();
+            }
+            history.push_back(number);
+        }
+        self.latest.insert(name.to_owned(), value);
+    }
+}
+
+/// Per-request cap so one hung endpoint can't stall its poll loop.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// One polled endpoint, from a `[[data_sources]]` table:
+///
+/// ```toml
+/// [[data_sources]]
+/// name = "price"
+/// url = "https://api.example.com/btc"
+/// selector = "$.bitcoin.usd"
+/// interval_secs = 60
+/// ```
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct DataSourceConfig {
+    /// Placeholder name: `{price}` in an entry.
+    pub name: String,
+    pub url: String,
+    /// Parse the body as JSON and pick out this value. Absent = use
+    /// the body as plain text, trimmed.
+    #[serde(default)]
+    pub selector: Option<String>,
+    #[serde(default = "default_interval_secs")]
+    pub interval_secs: u64,
+}
+
+fn default_interval_secs() -> u64 {
+    60
+}
+
+/// Poll every source on its own interval forever, writing results
+/// into `values`.
+pub async fn poll(sources: Vec<DataSourceConfig>, values: DataValues) -> anyhow::Result<()> {
+    let client = reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .context("build data client")?;
+    let mut tasks = JoinSet::new();
+    for source in sources {
+        let (client, values) = (client.clone(), values.clone());
+        tasks.spawn(async move {
+            let mut tick = tokio::time::interval(Duration::from_secs(source.interval_secs.max(1)));
+            loop {
+                tick.tick().await;
+                match fetch(&client, &source).await {
+                    Ok(value) => {
+                        values.write().record(&source.name, value);
+                    }
+                    Err(err) => {
+                        tracing::warn!(
+                            source = %source.name,
+                            error = ?err,
+                            "data poll failed; keeping last value"
+                        );
+                    }
+                }
+            }
+        });
+    }
+    while tasks.join_next().await.is_some() {}
+    Ok(())
+}
+
+async fn fetch(client: &reqwest::Client, source: &DataSourceConfig) -> anyhow::Result<String> {
+    let body = client
+        .get(&source.url)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let Some(selector) = source.selector.as_deref() else {
+        return Ok(body.trim().to_owned());
+    };
+    let json: JsonValue = serde_json::from_str(&body).context("parse JSON body")?;
+    select(&json, selector).with_context(|| format!("nothing at {selector}"))
+}
+
+/// The value at `selector` in `json`, as display text: strings as-is,
+/// anything else as compact JSON.
+///
+/// Selectors are a `JSONPath` subset: an optional leading `$`, then any
+/// mix of `.key`, `["key"]` and `[index]` — `$.data[0].price`.
+#[must_use]
+pub fn select(json: &JsonValue, selector: &str) -> Option<String> {
+    let mut rest = selector.trim().strip_prefix('$').unwrap_or(selector.trim());
+    let mut current = json;
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('[') {
+            let (inside, after) = after.split_once(']')?;
+            current = match inside.trim_matches('"').parse::<usize>() {
+                Ok(index) if !inside.starts_with('"') => current.get(index)?,
+                _ => current.get(inside.trim_matches('"'))?,
+            };
+            rest = after;
+        } else {
+            let after = rest.strip_prefix('.').unwrap_or(rest);
+            let end = after.find(['.', '[']).unwrap_or(after.len());
+            current = current.get(&after[..end])?;
+            rest = &after[end..];
+        }
+    }
+    Some(match current {
+        JsonValue::String(s) => s.clone(),
+        other => other.to_string(),
+    })
+}
+
+/// `template` with each `{name}` of a known source replaced by its
+/// value. Anything else in braces — markup tags, unknown names — is
+/// left alone. For a `markup` entry, a `{` in a value is doubled so
+/// polled text shows literally instead of opening a style tag (a
+/// lone `}` is already plain text there).
+#[must_use]
+pub fn substitute<S: BuildHasher>(
+    template: &str,
+    values: &HashMap<String, String, S>,
+    markup: bool,
+) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        let known = after
+            .find('}')
+            .and_then(|close| Some((values.get(&after[..close])?, close)));
+        if let Some((value, close)) = known {
+            if markup {
+                out.push_str(&value.replace('{', "{{"));
+            } else {
+                out.push_str(value);
+            }
+            rest = &after[close + 1..];
+        } else {
+            out.push('{');
+            rest = after;
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> JsonValue {
+        serde_json::json!({
+            "a": [{ "b": 1.5 }, { "b": "two" }],
+            "odd key": "spaced",
+            "nested": { "list": [1, 2] },
+        })
+    }
+
+    #[test]
+    fn select_walks_keys_and_indexes() {
+        let json = sample();
+        assert_eq!(select(&json, "$.a[0].b").as_deref(), Some("1.5"));
+        assert_eq!(select(&json, "a[1].b").as_deref(), Some("two"));
+        assert_eq!(select(&json, r#"$["odd key"]"#).as_deref(), Some("spaced"));
+        assert_eq!(
+            select(&json, r#"$.nested["list"][1]"#).as_deref(),
+            Some("2")
+        );
+    }
+
+    #[test]
+    fn select_misses_are_none() {
+        let json = sample();
+        assert_eq!(select(&json, "$.missing"), None);
+        assert_eq!(select(&json, "$.a[5].b"), None);
+        assert_eq!(select(&json, "$.a[0].b.c"), None);
+        assert_eq!(select(&json, "$.a[0"), None);
+    }
+
+    #[test]
+    fn select_non_scalars_as_compact_json() {
+        let json = sample();
+        assert_eq!(
+            select(&json, "$.nested").as_deref(),
+            Some(r#"{"list":[1,2]}"#)
+        );
+        assert_eq!(select(&json, "$.nested.list").as_deref(), Some("[1,2]"));
+    }
+
+    #[test]
+    fn substitute_fills_known_names_only() {
+        let values = HashMap::from([("price".to_owned(), "67012.5".to_owned())]);
+        assert_eq!(substitute("BTC {price}", &values, false), "BTC 67012.5");
+        assert_eq!(
+            substitute("{red}{price}{/} {volume} {", &values, true),
+            "{red}67012.5{/} {volume} {"
+        );
+    }
+
+    #[test]
+    fn substitute_escapes_values_for_markup() {
+        let values = HashMap::from([("msg".to_owned(), "{red}hi{/}".to_owned())]);
+        assert_eq!(substitute("{msg}", &values, false), "{red}hi{/}");
+        assert_eq!(substitute("{msg}", &values, true), "{{red}hi{{/}");
+        let spans = display_core::text::parse_markup(&substitute("{msg}", &values, true));
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].text, "{red}hi{/}");
+    }
+}
@@ -1,25 +1,36 @@
 use std::sync::Arc;
 use std::time::Instant;
 
-use chrono::{Local, Timelike};
+use chrono::{Datelike, Local, NaiveDateTime, Timelike};
 use chrono_tz::Tz;
+use embedded_graphics::geometry::Size;
 use display_core::{
     boot::BootScene,
-    clock::{ClockSceneConfig, ClockTime},
+    calibration::{Calibration, CalibrationLut},
+    chart::ChartScene,
+    clock::{ClockScene, ClockSceneConfig, ClockTime},
+    countdown::CountdownSceneConfig,
+    gauge::GaugeScene,
+    font::BdfFont,
     gif::GifScene,
     image::ImageScene,
     life::{Lattice, LifeSceneConfig},
+    playlist::{PlaylistItem, PlaylistScene, PlaylistTransition},
     setup::SetupScene,
     shapes::ShapesScene,
     test::TestScene,
-    text::TextScene,
-    Scene, Mode, PanelState,
+    text::{TextLayout, TextScene},
+    weather::WeatherSceneConfig,
+    transition::Transition,
+    BrightnessCurve, Scene, Mode, PanelState,
 };
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use std::path::Path;
 
+use crate::data::DataValues;
+use crate::schedule::{Schedule, ScheduleWindow};
 use crate::sink::{MatrixSink, PixelBuffer};
 use crate::state::State;
 use crate::telemetry::Metrics;
@@ -35,10 +46,13 @@ pub use display_core::{
 // Not `Eq`: `brightness` is an f32. Manual `Default` so brightness
 // defaults to full (1.0), not 0.0 — a derived default would render the
 // pre-first-fetch boot screen black.
+#[allow(clippy::struct_excessive_bools)]
 #[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
 pub struct Panel {
-    /// Unique identifier of the panel.
-    #[serde(skip_serializing)]
+    /// Unique identifier of the panel. Defaulted on deserialize so
+    /// the on-disk state cache (which can't round-trip it through this
+    /// type) still parses.
+    #[serde(skip_serializing, default)]
     pub id: String,
     /// Human-readable name for the panel.
     pub name: String,
@@ -55,7 +69,7 @@ pub struct Panel {
     pub flash: FlashState,
     /// When the panel was last updated. Compared against the previous
     /// value to decide whether to re-pull entries.
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing, default)]
     pub last_updated: String,
     /// Render mode: "text", "clock", … . Drives the dispatch in `drive`.
     /// `panels.mode` is `not null default 'text'` server-side, so the
@@ -69,6 +83,38 @@ pub struct Panel {
     /// Defaults to full for rows predating the column.
     #[serde(default = "default_brightness")]
     pub brightness: f32,
+    /// How `brightness` maps onto pixel values. Linear for rows
+    /// predating the column.
+    #[serde(default)]
+    pub brightness_curve: BrightnessCurve,
+    /// Dither dimmed pixels so low brightness keeps gradients. Off for
+    /// rows predating the column.
+    #[serde(default)]
+    pub dither: bool,
+    /// Time-of-day windows that override `is_off`, `brightness` and
+    /// `mode` while active. Empty for rows predating the column.
+    #[serde(default)]
+    pub schedule: Schedule,
+    /// How the render loop blends into new content when the mode or
+    /// `last_updated` changes. A cut, as before the column existed, until
+    /// the dash picks a kind.
+    #[serde(default)]
+    pub transition: Transition,
+    /// Clockwise rotation in degrees (0/90/180/270) for panels mounted
+    /// upside-down or in portrait. 0 for rows predating the column.
+    #[serde(default)]
+    pub rotation: u16,
+    /// Mirror the frame left-right, after rotating.
+    #[serde(default)]
+    pub flip_horizontal: bool,
+    /// Mirror the frame top-bottom, after rotating.
+    #[serde(default)]
+    pub flip_vertical: bool,
+    /// Overrides for the Pi's `[calibration]`, key by key (`{"gain":
+    /// {"r": 1, "g": 0.8, "b": 0.7}}`). Empty for rows predating the
+    /// column.
+    #[serde(default)]
+    pub calibration: JsonValue,
 }
 
 fn default_brightness() -> f32 {
@@ -88,6 +134,14 @@ impl Default for Panel {
             mode: String::new(),
             mode_config: JsonValue::default(),
             brightness: 1.0,
+            brightness_curve: BrightnessCurve::default(),
+            dither: false,
+            schedule: Schedule::default(),
+            transition: Transition::default(),
+            rotation: 0,
+            flip_horizontal: false,
+            flip_vertical: false,
+            calibration: JsonValue::default(),
         }
     }
 }
@@ -95,6 +149,8 @@ impl Default for Panel {
 pub async fn drive(
     mut sink: Box<dyn MatrixSink>,
     state: Arc<RwLock<State>>,
+    data: DataValues,
+    calibration: Calibration,
     metrics: Arc<Metrics>,
 ) -> anyhow::Result<()> {
     tracing::info!("Initializing display...");
@@ -102,43 +158,101 @@ pub async fn drive(
     let mut buffer = PixelBuffer::new(width, height);
 
     let mut step: usize = 0;
-    let mut life_state: Option<LifeState> = None;
+    let mut life_state = LifeSim::new(width, height);
+    let mut calibration = ActiveCalibration::new(calibration);
     let mut config_cache = ConfigCache::default();
     // Most recent clock sample. Frozen while the panel is paused so
     // the displayed time doesn't advance even though render() is
     // still running.
-    let mut last_clock_now: Option<ClockTime> = None;
+    let mut last_clock_now: Option<ClockSample> = None;
+    // Wall clock for countdowns, in Unix milliseconds. Frozen while
+    // paused, like the clock sample.
+    let mut countdown_now = chrono::Utc::now().timestamp_millis();
+    // Previous frame, kept so a content change can blend out of it.
+    let mut scene_key: Option<SceneKey> = None;
+    let mut previous: Option<(Scene, usize)> = None;
+    let mut transition: Option<ActiveTransition> = None;
     loop {
         let frame_started = Instant::now();
 
         // Hold the read lock only long enough to build the frame input;
         // cache covers the heavy parse path.
-        let (mode, panel_state) = {
+        let (mode, panel_state, changed) = {
             let snapshot = state.read();
-            let panel_state = PanelState {
-                is_paused: snapshot.panel.is_paused,
-                is_off: snapshot.panel.is_off,
-                flash: snapshot.panel.flash.clone(),
-                brightness: snapshot.panel.brightness,
+            let schedule = &snapshot.panel.schedule;
+            let scheduled = if schedule.windows.is_empty() {
+                None
+            } else {
+                schedule.active(local_now(schedule.timezone.as_deref()))
             };
+            let window = scheduled.map(|(_, window)| window);
+            if !snapshot.panel.is_paused {
+                countdown_now = chrono::Utc::now().timestamp_millis();
+            }
+            let ambient = data.read().ambient_brightness;
+            let panel_state = panel_state(&snapshot.panel, window, ambient);
+            // Life steps a lattice the size of the canvas renderers
+            // see, which a quarter turn swaps.
+            let logical = panel_state.logical_size(Size::new(width, height));
+            life_state.fit(logical.width, logical.height);
+            calibration.update(&snapshot.panel.calibration);
             let mode = build_mode(
                 &snapshot,
                 &mut life_state,
                 &mut config_cache,
                 &mut last_clock_now,
+                countdown_now,
+                &data,
+                scheduled,
+                step,
             );
-            (mode, panel_state)
+            let changed = SceneKey::update(
+                &mut scene_key,
+                &snapshot.panel,
+                scheduled.map(|(index, _)| index),
+            )
+            .then_some(snapshot.panel.transition);
+            (mode, panel_state, changed)
         };
         let frame = Scene { mode, panel: panel_state };
 
+        if let (Some(spec), Some((from, from_step))) = (changed, previous.take()) {
+            transition = Some(ActiveTransition {
+                spec,
+                elapsed: 0,
+                from,
+                from_step,
+            });
+        }
         // PixelBuffer's DrawTarget impl is Infallible — `render`
         // can't fail here, so unwrap is fine.
-        display_core::render(&frame, step, &mut buffer).expect("infallible draw target");
+        match &mut transition {
+            Some(t) if !t.spec.is_done(t.elapsed) => {
+                display_core::transition::render(
+                    &t.spec,
+                    t.elapsed,
+                    &t.from,
+                    t.from_step,
+                    &frame,
+                    step,
+                    &mut buffer,
+                )
+                .expect("infallible draw target");
+                t.elapsed += 1;
+            }
+            _ => {
+                transition = None;
+                display_core::render(&frame, step, &mut buffer).expect("infallible draw target");
+            }
+        }
 
+        let frame_step = step;
         if !frame.panel.is_paused && !frame.panel.is_off {
             step += 1;
         }
+        previous = Some((frame, frame_step));
 
+        calibration.apply(&mut buffer);
         sink.present(&buffer)?;
         metrics
             .frame_time_ms
@@ -146,6 +260,104 @@ pub async fn drive(
     }
 }
 
+/// Mode-independent render input for `panel`, with the active
+/// schedule window's overrides applied. `ambient` scales the panel's
+/// own brightness, not a window's.
+fn panel_state(panel: &Panel, window: Option<&ScheduleWindow>, ambient: Option<f32>) -> PanelState {
+    PanelState {
+        is_paused: panel.is_paused,
+        is_off: window.and_then(|w| w.is_off).unwrap_or(panel.is_off),
+        flash: panel.flash.clone(),
+        brightness: window
+            .and_then(|w| w.brightness)
+            .unwrap_or(panel.brightness * ambient.unwrap_or(1.0)),
+        brightness_curve: panel.brightness_curve,
+        dither: panel.dither,
+        rotation: panel.rotation,
+        flip_horizontal: panel.flip_horizontal,
+        flip_vertical: panel.flip_vertical,
+    }
+}
+
+/// What a transition is keyed on: when any of these changes between
+/// frames, the old scene blends into the new one.
+#[derive(PartialEq, Eq)]
+struct SceneKey {
+    mode: String,
+    last_updated: String,
+    /// Active schedule window, which can swap the mode without the
+    /// panel row changing.
+    window: Option<usize>,
+}
+
+impl SceneKey {
+    /// Store the key for this frame; `true` if it differs from the
+    /// last one.
+    fn update(key: &mut Option<SceneKey>, panel: &Panel, window: Option<usize>) -> bool {
+        let unchanged = key.as_ref().is_some_and(|k| {
+            k.mode == panel.mode && k.last_updated == panel.last_updated && k.window == window
+        });
+        if !unchanged {
+            *key = Some(SceneKey {
+                mode: panel.mode.clone(),
+                last_updated: panel.last_updated.clone(),
+                window,
+            });
+        }
+        !unchanged
+    }
+}
+
+/// A transition in flight, blending out of the last frame drawn
+/// before the change (held still at its step).
+struct ActiveTransition {
+    spec: Transition,
+    elapsed: usize,
+    from: Scene,
+    from_step: usize,
+}
+
+/// Register every `.bdf` and `.pcf` file in `dir` as a selectable font
+/// named after its file stem. Unreadable or malformed fonts are logged
+/// and skipped — text using them falls back to the default font. A
+/// gzipped `.pcf.gz` is warned about rather than read; gunzip it.
+pub fn load_fonts(dir: &Path) {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) => {
+            tracing::warn!(error = %err, dir = %dir.display(), "couldn't read font dir");
+            return;
+        }
+    };
+    for path in entries.filter_map(Result::ok).map(|e| e.path()) {
+        let Some(name) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        let parsed = match path.extension().and_then(|e| e.to_str()) {
+            Some("bdf") => std::fs::read_to_string(&path)
+                .map_err(anyhow::Error::from)
+                .and_then(|source| Ok(BdfFont::parse(&source)?)),
+            Some("pcf") => std::fs::read(&path)
+                .map_err(anyhow::Error::from)
+                .and_then(|data| Ok(BdfFont::parse_pcf(&data)?)),
+            Some("gz") if Path::new(name).extension().is_some_and(|e| e == "pcf") => {
+                tracing::warn!(path = %path.display(), "skipping compressed PCF font; gunzip it");
+                continue;
+            }
+            _ => continue,
+        };
+        match parsed {
+            Ok(font) => {
+                tracing::info!(font = name, "Loaded font");
+                display_core::font::register_bdf(name, font);
+            }
+            Err(err) => {
+                tracing::warn!(error = %err, path = %path.display(), "couldn't load font");
+            }
+        }
+    }
+}
+
 /// Read the wifi-setup marker file. wifi-setup writes this when it
 /// brings the AP up and removes it on successful STA connect; the
 /// content is two lines: the AP SSID and the portal URL. Absence =
@@ -173,8 +385,86 @@ fn read_setup_marker() -> Option<SetupScene> {
     })
 }
 
+/// Life mode's simulation, sized to the canvas. The lattice only
+/// exists while a life scene is showing.
+struct LifeSim {
+    width: u16,
+    height: u16,
+    state: Option<LifeState>,
+}
+
+impl LifeSim {
+    fn new(width: u32, height: u32) -> Self {
+        Self {
+            width: u16::try_from(width).unwrap_or(u16::MAX),
+            height: u16::try_from(height).unwrap_or(u16::MAX),
+            state: None,
+        }
+    }
+
+    /// Resize to a `width`×`height` canvas, starting over from a new
+    /// seed if that changes anything.
+    fn fit(&mut self, width: u32, height: u32) {
+        let fitted = Self::new(width, height);
+        if (fitted.width, fitted.height) != (self.width, self.height) {
+            *self = fitted;
+        }
+    }
+
+    /// Drop the lattice; the next life scene starts from a new seed.
+    fn stop(&mut self) {
+        self.state = None;
+    }
+}
+
+/// The Pi's `[calibration]` with the panel row's overrides on top,
+/// baked once per change of overrides.
+struct ActiveCalibration {
+    base: Calibration,
+    overrides: JsonValue,
+    /// `None` when the result changes nothing.
+    lut: Option<CalibrationLut>,
+}
+
+impl ActiveCalibration {
+    fn new(base: Calibration) -> Self {
+        Self {
+            base,
+            overrides: JsonValue::Null,
+            lut: (!base.is_identity()).then(|| base.lut()),
+        }
+    }
+
+    fn update(&mut self, overrides: &JsonValue) {
+        if *overrides == self.overrides {
+            return;
+        }
+        self.overrides = overrides.clone();
+        let calibration = match overrides {
+            JsonValue::Object(overrides) if !overrides.is_empty() => {
+                let Ok(JsonValue::Object(mut merged)) = serde_json::to_value(self.base) else {
+                    unreachable!("Calibration serializes to a JSON object")
+                };
+                merged.extend(overrides.clone());
+                serde_json::from_value(JsonValue::Object(merged)).unwrap_or_else(|err| {
+                    tracing::warn!(error = %err, "invalid panel calibration; using config.toml's");
+                    self.base
+                })
+            }
+            _ => self.base,
+        };
+        self.lut = (!calibration.is_identity()).then(|| calibration.lut());
+    }
+
+    fn apply(&self, buffer: &mut PixelBuffer) {
+        if let Some(lut) = &self.lut {
+            buffer.calibrate(lut);
+        }
+    }
+}
+
 /// Driver-local state for life mode. Held across frames so the
-/// lattice can evolve between renders. Reset to None when the panel
+/// lattice can evolve between renders. Dropped when the panel
 /// switches away from life mode.
 struct LifeState {
     lattice: Lattice,
@@ -194,34 +484,95 @@ const LIFE_RESEED_GENERATIONS: u32 = 1500;
 /// Look up an IANA timezone (e.g. "America/Los_Angeles") and return
 /// the current local time there. Falls back to system local time
 /// when the timezone string is missing or doesn't parse.
+pub(crate) fn local_now(timezone: Option<&str>) -> NaiveDateTime {
+    timezone
+        .filter(|s| !s.is_empty())
+        .and_then(|tz_str| tz_str.parse::<Tz>().ok())
+        .map_or_else(
+            || Local::now().naive_local(),
+            |tz| chrono::Utc::now().with_timezone(&tz).naive_local(),
+        )
+}
+
+/// Current wall-clock time in `timezone` as a [`ClockTime`]; see
+/// [`local_now`].
 fn sample_time(timezone: Option<&str>) -> ClockTime {
-    let (h, m, s) = if let Some(tz_str) = timezone.filter(|s| !s.is_empty()) {
-        match tz_str.parse::<Tz>() {
-            Ok(tz) => {
-                let now = chrono::Utc::now().with_timezone(&tz);
-                (now.hour(), now.minute(), now.second())
-            }
-            Err(_) => {
-                let now = Local::now();
-                (now.hour(), now.minute(), now.second())
+    let now = local_now(timezone);
+    // chrono returns u32 for these but they all fit in u8 (max 59).
+    // `try_from` here documents the invariant and avoids a silent wrap
+    // if chrono ever returns something pathological.
+    ClockTime {
+        hour: u8::try_from(now.hour()).unwrap_or(0),
+        minute: u8::try_from(now.minute()).unwrap_or(0),
+        second: u8::try_from(now.second()).unwrap_or(0),
+        year: now.year(),
+        month: u8::try_from(now.month()).unwrap_or(1),
+        day: u8::try_from(now.day()).unwrap_or(1),
+        weekday: u8::try_from(now.weekday().num_days_from_monday()).unwrap_or(0),
+    }
+}
+
+/// One clock sample: the main timezone's time and each world-clock
+/// zone's, in order.
+#[derive(Clone)]
+struct ClockSample {
+    now: ClockTime,
+    zones: Vec<ClockTime>,
+}
+
+impl ClockSample {
+    fn take(config: &ClockSceneConfig) -> Self {
+        Self {
+            now: sample_time(config.timezone.as_deref()),
+            zones: config
+                .zones
+                .iter()
+                .map(|zone| sample_time(Some(&zone.timezone)))
+                .collect(),
+        }
+    }
+}
+
+/// Clock scene for the current time. Frozen at `last_now` while the
+/// panel is paused — without this, `sample_time` runs every frame and
+/// the displayed time keeps advancing even though every other
+/// animated mode honours the freeze via the static `step`.
+fn clock_frame(
+    config: &ClockSceneConfig,
+    is_paused: bool,
+    last_now: &mut Option<ClockSample>,
+) -> ClockScene {
+    let sample = match last_now {
+        Some(sample) if is_paused => sample.clone(),
+        _ => {
+            let sampled = ClockSample::take(config);
+            if !is_paused {
+                *last_now = Some(sampled.clone());
             }
+            sampled
         }
-    } else {
-        let now = Local::now();
-        (now.hour(), now.minute(), now.second())
     };
-    // chrono returns u32 for HMS but they fit in u8 (max 59). `try_from`
-    // here documents the invariant and avoids a silent wrap if chrono
-    // ever returns something pathological.
-    ClockTime {
-        hour: u8::try_from(h).unwrap_or(0),
-        minute: u8::try_from(m).unwrap_or(0),
-        second: u8::try_from(s).unwrap_or(0),
+    config.clone().into_frame(sample.now, &sample.zones)
+}
+
+/// Advance the life simulation by one frame (stepping the lattice
+/// every `step_interval_frames`) and return the current lattice.
+fn tick_life<'a>(life_state: &'a mut LifeSim, config: &LifeSceneConfig) -> &'a Lattice {
+    let interval = config.step_interval_frames.max(1);
+    let (width, height) = (life_state.width, life_state.height);
+    let s = life_state
+        .state
+        .get_or_insert_with(|| LifeState::new(width, height));
+    s.frames_since_step += 1;
+    if s.frames_since_step >= interval {
+        s.frames_since_step = 0;
+        s.advance();
     }
+    &s.lattice
 }
 
 impl LifeState {
-    fn new(width: u8, height: u8) -> Self {
+    fn new(width: u16, height: u16) -> Self {
         let mut s = Self {
             lattice: Lattice::new(width, height),
             frames_since_step: 0,
@@ -272,7 +623,8 @@ impl LifeState {
 }
 
 /// Caches the parsed config for immutable-payload modes (image /
-/// paint / gif / shapes / test) keyed on `(mode, last_updated)`.
+/// paint / gif / shapes / test, and playlists built from them) keyed
+/// on `(mode, last_updated)`.
 /// Re-parsing 720KB jsonb per frame burns the Pi Zero W's frame
 /// budget; cache hits are a Vec<u8> memcpy.
 ///
@@ -291,6 +643,106 @@ enum CachedConfig {
     Gif(Arc<GifScene>),
     Shapes(ShapesScene),
     Test(TestScene),
+    Playlist(Vec<CachedPlaylistItem>),
+}
+
+/// Persisted shape of a playlist-mode `mode_config`: each item is the
+/// `mode` / `mode_config` pair a panel row would carry, plus how long
+/// to show it.
+#[derive(Default, Deserialize)]
+struct PlaylistConfig {
+    #[serde(default)]
+    items: Vec<PlaylistItemConfig>,
+}
+
+#[derive(Deserialize)]
+struct PlaylistItemConfig {
+    mode: String,
+    #[serde(default)]
+    mode_config: JsonValue,
+    duration_steps: usize,
+    #[serde(default)]
+    transition: PlaylistTransition,
+}
+
+/// A playlist item with its config parsed once. Clock, countdown,
+/// life, text, chart, gauge and weather children carry live data
+/// (time, lattice, entries, polled values), so they're finished per
+/// frame; everything else is built up front.
+struct CachedPlaylistItem {
+    child: PlaylistChild,
+    duration_steps: usize,
+    transition: PlaylistTransition,
+}
+
+enum PlaylistChild {
+    Static(Mode),
+    Clock {
+        config: ClockSceneConfig,
+        /// Per-item pause freeze, same as `last_clock_now` for a
+        /// top-level clock — items may sit in different timezones.
+        last_now: Option<ClockSample>,
+    },
+    Countdown(CountdownSceneConfig),
+    Chart {
+        scene: ChartScene,
+        source: Option<String>,
+    },
+    Gauge {
+        scene: GaugeScene,
+        source: Option<String>,
+    },
+    Life(LifeSceneConfig),
+    Weather(WeatherSceneConfig),
+    Text(TextLayout),
+    Ticker(TextLayout),
+}
+
+/// The driver-side half of a chart or gauge `mode_config`: which
+/// data source, if any, supplies its samples or value.
+#[derive(Default, Deserialize)]
+struct SourceBinding {
+    #[serde(default)]
+    source: Option<String>,
+}
+
+/// A `mode_config` as `T`, or the default when it doesn't parse.
+fn parsed<T: serde::de::DeserializeOwned + Default>(config: &JsonValue) -> T {
+    serde_json::from_value(config.clone()).unwrap_or_default()
+}
+
+impl PlaylistChild {
+    fn parse(mode: &str, mode_config: &JsonValue) -> Self {
+        match mode {
+            "clock" => Self::Clock {
+                config: parsed(mode_config),
+                last_now: None,
+            },
+            "countdown" => Self::Countdown(parsed(mode_config)),
+            "chart" => {
+                let SourceBinding { source } = parsed(mode_config);
+                Self::Chart {
+                    scene: parsed(mode_config),
+                    source,
+                }
+            }
+            "gauge" => {
+                let SourceBinding { source } = parsed(mode_config);
+                Self::Gauge {
+                    scene: parsed(mode_config),
+                    source,
+                }
+            }
+            "life" => Self::Life(parsed(mode_config)),
+            "weather" => Self::Weather(parsed(mode_config)),
+            "image" | "paint" => Self::Static(Mode::Image(Arc::new(parsed(mode_config)))),
+            "gif" => Self::Static(Mode::Gif(Arc::new(parsed(mode_config)))),
+            "shapes" => Self::Static(Mode::Shapes(parsed(mode_config))),
+            "test" => Self::Static(Mode::Test(parsed(mode_config))),
+            "ticker" => Self::Ticker(parsed(mode_config)),
+            _ => Self::Text(parsed(mode_config)),
+        }
+    }
 }
 
 impl ConfigCache {
@@ -302,7 +754,7 @@ impl ConfigCache {
         mode: &str,
         last_updated: &str,
         mode_config: &JsonValue,
-    ) -> &'a CachedConfig {
+    ) -> &'a mut CachedConfig {
         let want = (mode.to_owned(), last_updated.to_owned());
         if self.key.as_ref() != Some(&want) || self.parsed.is_none() {
             let parsed = match mode {
@@ -318,13 +770,31 @@ impl ConfigCache {
                 "test" => CachedConfig::Test(
                     serde_json::from_value(mode_config.clone()).unwrap_or_default(),
                 ),
+                "playlist" => {
+                    let config: PlaylistConfig =
+                        serde_json::from_value(mode_config.clone()).unwrap_or_default();
+                    CachedConfig::Playlist(
+                        config
+                            .items
+                            .into_iter()
+                            // Playlists don't nest; display-core would
+                            // render the slot blank anyway.
+                            .filter(|item| item.mode != "playlist")
+                            .map(|item| CachedPlaylistItem {
+                                child: PlaylistChild::parse(&item.mode, &item.mode_config),
+                                duration_steps: item.duration_steps,
+                                transition: item.transition,
+                            })
+                            .collect(),
+                    )
+                }
                 _ => unreachable!("ConfigCache::fetch only handles cached modes"),
             };
             self.key = Some(want);
             self.parsed = Some(parsed);
         }
         self.parsed
-            .as_ref()
+            .as_mut()
             .expect("just populated by the branch above")
     }
 }
@@ -333,108 +803,254 @@ impl ConfigCache {
 /// pre-conditions short-circuit the configured mode:
 ///   1. wifi-setup is running its onboarding AP — show the setup
 ///      frame (SSID + portal URL) so the user can join from a phone.
-///   2. State sync hasn't resolved a panel id yet — show the boot
-///      frame as a "we're alive, just waking up" indicator.
+///   2. Nothing has populated the state yet (no sync, no cached
+///      snapshot, no local API write) — show the boot frame as a
+///      "we're alive, just waking up" indicator.
 /// Falls back to text mode on unknown modes so a misconfigured
-/// panel doesn't black out.
+/// panel doesn't black out. `step` picks which playlist item to
+/// build.
+#[allow(clippy::too_many_arguments)]
 fn build_mode(
     snapshot: &State,
-    life_state: &mut Option<LifeState>,
+    life_state: &mut LifeSim,
     config_cache: &mut ConfigCache,
-    last_clock_now: &mut Option<ClockTime>,
+    last_clock_now: &mut Option<ClockSample>,
+    countdown_now: i64,
+    data: &DataValues,
+    scheduled: Option<(usize, &ScheduleWindow)>,
+    step: usize,
 ) -> Mode {
     if let Some(setup_frame) = read_setup_marker() {
-        *life_state = None;
+        life_state.stop();
         return Mode::Setup(setup_frame);
     }
-    if snapshot.panel.id.is_empty() {
-        *life_state = None;
+    if snapshot.panel.id.is_empty() && snapshot.panel.last_updated.is_empty() {
+        life_state.stop();
         return Mode::Boot(BootScene::default());
     }
-    match snapshot.panel.mode.as_str() {
+    // A schedule window that switches mode stands in for the panel's
+    // own mode/config. Its cache key carries the window index so the
+    // config cache can't mistake it for the panel's own config.
+    let scheduled_key;
+    let (mode, mode_config, last_updated) = match scheduled {
+        Some((index, ScheduleWindow { mode: Some(mode), mode_config, .. })) => {
+            scheduled_key = format!("{}#schedule:{index}", snapshot.panel.last_updated);
+            (
+                mode.as_str(),
+                mode_config.as_ref().unwrap_or(&JsonValue::Null),
+                scheduled_key.as_str(),
+            )
+        }
+        _ => (
+            snapshot.panel.mode.as_str(),
+            &snapshot.panel.mode_config,
+            snapshot.panel.last_updated.as_str(),
+        ),
+    };
+    match mode {
         "clock" => {
-            *life_state = None;
+            life_state.stop();
             // Tiny payload — cheaper to parse than to manage in the
             // cache, and `now` shifts every frame anyway so we'd
             // rebuild Mode::Clock either way.
-            let config: ClockSceneConfig =
-                serde_json::from_value(snapshot.panel.mode_config.clone()).unwrap_or_default();
-            // Freeze the clock when the panel is paused — without
-            // this, sample_time runs every frame and the displayed
-            // time keeps advancing even though every other animated
-            // mode honours the freeze via the static `step`.
-            let now = if snapshot.panel.is_paused {
-                last_clock_now.unwrap_or_else(|| sample_time(config.timezone.as_deref()))
+            let config: ClockSceneConfig = parsed(mode_config);
+            Mode::Clock(clock_frame(&config, snapshot.panel.is_paused, last_clock_now))
+        }
+        "countdown" => {
+            life_state.stop();
+            // Parsed per frame for the same reason as clock.
+            let config: CountdownSceneConfig = parsed(mode_config);
+            Mode::Countdown(config.into_frame(countdown_now))
+        }
+        "chart" | "gauge" => {
+            life_state.stop();
+            let SourceBinding { source } = parsed(mode_config);
+            let source = source.as_deref();
+            if mode == "chart" {
+                chart_mode(parsed(mode_config), source, data)
             } else {
-                let sampled = sample_time(config.timezone.as_deref());
-                *last_clock_now = Some(sampled);
-                sampled
-            };
-            Mode::Clock(config.into_frame(now))
+                gauge_mode(parsed(mode_config), source, data)
+            }
+        }
+        "weather" => {
+            life_state.stop();
+            let config: WeatherSceneConfig = parsed(mode_config);
+            Mode::Weather(config.into_frame(data.read().weather))
         }
         "life" => {
-            let config: LifeSceneConfig =
-                serde_json::from_value(snapshot.panel.mode_config.clone()).unwrap_or_default();
-            let interval = config.step_interval_frames.max(1);
-            let s = life_state.get_or_insert_with(|| LifeState::new(64, 64));
-            s.frames_since_step += 1;
-            if s.frames_since_step >= interval {
-                s.frames_since_step = 0;
-                s.advance();
-            }
-            Mode::Life(config.into_frame(&s.lattice))
+            let config: LifeSceneConfig = parsed(mode_config);
+            let lattice = tick_life(life_state, &config);
+            Mode::Life(config.into_frame(lattice))
         }
         "image" | "paint" => {
-            *life_state = None;
-            match config_cache.fetch(
-                snapshot.panel.mode.as_str(),
-                snapshot.panel.last_updated.as_str(),
-                &snapshot.panel.mode_config,
-            ) {
+            life_state.stop();
+            match config_cache.fetch(mode, last_updated, mode_config) {
                 CachedConfig::Image(arc) => Mode::Image(Arc::clone(arc)),
                 _ => unreachable!("cache returns the variant we asked for"),
             }
         }
         "gif" => {
-            *life_state = None;
-            match config_cache.fetch(
-                "gif",
-                snapshot.panel.last_updated.as_str(),
-                &snapshot.panel.mode_config,
-            ) {
+            life_state.stop();
+            match config_cache.fetch("gif", last_updated, mode_config) {
                 CachedConfig::Gif(arc) => Mode::Gif(Arc::clone(arc)),
                 _ => unreachable!("cache returns the variant we asked for"),
             }
         }
         "shapes" => {
-            *life_state = None;
-            match config_cache.fetch(
-                "shapes",
-                snapshot.panel.last_updated.as_str(),
-                &snapshot.panel.mode_config,
-            ) {
+            life_state.stop();
+            match config_cache.fetch("shapes", last_updated, mode_config) {
                 CachedConfig::Shapes(frame) => Mode::Shapes(frame.clone()),
                 _ => unreachable!("cache returns the variant we asked for"),
             }
         }
         "test" => {
-            *life_state = None;
-            match config_cache.fetch(
-                "test",
-                snapshot.panel.last_updated.as_str(),
-                &snapshot.panel.mode_config,
-            ) {
+            life_state.stop();
+            match config_cache.fetch("test", last_updated, mode_config) {
                 CachedConfig::Test(frame) => Mode::Test(frame.clone()),
                 _ => unreachable!("cache returns the variant we asked for"),
             }
         }
+        "playlist" => {
+            let CachedConfig::Playlist(items) = config_cache.fetch(
+                "playlist",
+                last_updated,
+                mode_config,
+            ) else {
+                unreachable!("cache returns the variant we asked for")
+            };
+            build_playlist(items, snapshot, life_state, countdown_now, data, step)
+        }
         _ => {
-            *life_state = None;
-            Mode::Text(TextScene {
-                entries: snapshot.entries.clone(),
-                scroll: snapshot.panel.scroll,
-            })
+            life_state.stop();
+            // Layout is a handful of fields; parse per frame like clock.
+            // Ticker mode is text mode with live data filled in.
+            let data = (mode == "ticker").then_some(data);
+            text_mode(snapshot, parsed(mode_config), data)
+        }
+    }
+}
+
+/// Text mode over the snapshot's entries, laid out per the text-mode
+/// `mode_config`. With `data` (ticker mode), `{name}` placeholders in
+/// the entries are filled from the polled sources.
+fn text_mode(snapshot: &State, layout: TextLayout, data: Option<&DataValues>) -> Mode {
+    let mut entries = snapshot.entries.clone();
+    if let Some(data) = data {
+        let values = data.read();
+        for entry in &mut entries {
+            entry.text =
+                crate::data::substitute(&entry.text, &values.latest, entry.options.markup);
         }
     }
+    Mode::Text(TextScene {
+        entries,
+        scroll: snapshot.panel.scroll,
+        layout,
+    })
+}
+
+/// Chart mode, its samples taken from the `source` data source's
+/// history when it names one. A source with no numeric polls yet
+/// plots nothing rather than the config's placeholder samples.
+fn chart_mode(mut chart: ChartScene, source: Option<&str>, data: &DataValues) -> Mode {
+    if let Some(source) = source {
+        chart.samples = data
+            .read()
+            .history
+            .get(source)
+            .map(|history| history.iter().copied().collect())
+            .unwrap_or_default();
+    }
+    Mode::Chart(chart)
+}
+
+/// Gauge mode, its value taken from the `source` data source's latest
+/// poll when it names one. Until that parses as a number the gauge
+/// keeps the config's own value.
+fn gauge_mode(mut gauge: GaugeScene, source: Option<&str>, data: &DataValues) -> Mode {
+    let latest = source.and_then(|source| data.read().latest.get(source)?.parse().ok());
+    if let Some(value) = latest {
+        gauge.value = value;
+    }
+    Mode::Gauge(gauge)
 }
 
+/// Finish a cached playlist for this frame. Only the item on screen
+/// at `step` — plus the one after it while a fade runs out — gets its
+/// live data (time, lattice, entries, data) filled in; the rest keep a
+/// placeholder, since playlist render only draws the active item.
+fn build_playlist(
+    items: &mut [CachedPlaylistItem],
+    snapshot: &State,
+    life_state: &mut LifeSim,
+    countdown_now: i64,
+    data: &DataValues,
+    step: usize,
+) -> Mode {
+    // One shared lattice serves every life item. It only exists while
+    // the playlist has one, and only ticks while one is being built.
+    if !items
+        .iter()
+        .any(|item| matches!(item.child, PlaylistChild::Life(_)))
+    {
+        life_state.stop();
+    }
+    let mut scene = PlaylistScene {
+        items: items
+            .iter()
+            .map(|item| PlaylistItem {
+                mode: Mode::default(),
+                duration_steps: item.duration_steps,
+                transition: item.transition,
+            })
+            .collect(),
+    };
+    let Some((index, local_step)) = scene.active(step) else {
+        return Mode::Playlist(scene);
+    };
+    let fading_out = match items[index].transition {
+        PlaylistTransition::Fade { steps } => {
+            local_step + steps.min(items[index].duration_steps / 2) >= items[index].duration_steps
+        }
+        PlaylistTransition::Cut => false,
+    };
+    let next = (1..items.len())
+        .map(|offset| (index + offset) % items.len())
+        .find(|&i| items[i].duration_steps > 0)
+        .filter(|_| fading_out);
+    let shown = std::iter::once(index).chain(next);
+
+    let life_config = shown.clone().find_map(|i| match &items[i].child {
+        PlaylistChild::Life(config) => Some(config.clone()),
+        _ => None,
+    });
+    let lattice = life_config.map(|config| tick_life(life_state, &config));
+    for i in shown {
+        scene.items[i].mode = match &mut items[i].child {
+            PlaylistChild::Static(mode) => mode.clone(),
+            PlaylistChild::Clock { config, last_now } => {
+                Mode::Clock(clock_frame(config, snapshot.panel.is_paused, last_now))
+            }
+            PlaylistChild::Life(config) => Mode::Life(
+                config
+                    .clone()
+                    .into_frame(lattice.expect("ticked above when a life item is shown")),
+            ),
+            PlaylistChild::Countdown(config) => {
+                Mode::Countdown(config.clone().into_frame(countdown_now))
+            }
+            PlaylistChild::Text(layout) => text_mode(snapshot, *layout, None),
+            PlaylistChild::Ticker(layout) => text_mode(snapshot, *layout, Some(data)),
+            PlaylistChild::Chart { scene, source } => {
+                chart_mode(scene.clone(), source.as_deref(), data)
+            }
+            PlaylistChild::Gauge { scene, source } => {
+                gauge_mode(scene.clone(), source.as_deref(), data)
+            }
+            PlaylistChild::Weather(config) => {
+                Mode::Weather(config.clone().into_frame(data.read().weather))
+            }
+        };
+    }
+    Mode::Playlist(scene)
+}
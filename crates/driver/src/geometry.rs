@@ -0,0 +1,330 @@
+//! Panel geometry: how chained panels add up to the canvas we render.
+//!
+//! The hardware sees `chain_length` panels of `cols`×`rows` on each of
+//! `parallel` outputs — one long strip per output, stacked. Walls are
+//! rarely wired that way, so `pixel_mapper` folds that strip into the
+//! shape actually on the wall. Renderers only ever see the resulting
+//! virtual canvas ([`MatrixConfig::canvas_size`]); the sink maps each
+//! virtual pixel back onto the chain ([`MatrixConfig::to_physical`]).
+//!
+//! Mappers apply in order, each to the canvas the previous one made:
+//!
+//! * `U-mapper` — the chain runs out along the top half of the wall
+//!   and back, upside down, along the bottom half. Halves the width,
+//!   doubles the height.
+//! * `Serpentine:N` — the chain snakes across `N` rows of panels,
+//!   first panel top-left, every other row upside down.
+//! * `Rotate:90` / `180` / `270` — clockwise.
+//! * `Mirror:H` / `Mirror:V` — flip left-right / top-bottom.
+//!
+//! Folds have to split the canvas on panel boundaries and come before
+//! any quarter turn; [`MatrixConfig::validate`] rejects anything else.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Panel wiring, from a `[matrix]` table. Defaults to one 64×64
+/// panel.
+///
+/// ```toml
+/// [matrix]
+/// rows = 32
+/// cols = 64
+/// chain_length = 3   # a 192×32 strip
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize)]
+#[serde(default)]
+pub struct MatrixConfig {
+    /// Pixel rows on one panel.
+    pub rows: u32,
+    /// Pixel columns on one panel.
+    pub cols: u32,
+    /// Panels daisy-chained on each output.
+    pub chain_length: u32,
+    /// Chains driven side by side, one per HAT output.
+    pub parallel: u32,
+    pub pixel_mapper: Vec<PixelMapper>,
+}
+
+impl Default for MatrixConfig {
+    fn default() -> Self {
+        Self {
+            rows: 64,
+            cols: 64,
+            chain_length: 1,
+            parallel: 1,
+            pixel_mapper: Vec::new(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize)]
+#[serde(try_from = "String")]
+pub enum PixelMapper {
+    UMapper,
+    Serpentine(u32),
+    /// Clockwise degrees: 90, 180 or 270.
+    Rotate(u32),
+    /// `true` = horizontal (left-right), `false` = vertical.
+    Mirror(bool),
+}
+
+impl FromStr for PixelMapper {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, param) = s.trim().split_once(':').unwrap_or((s.trim(), ""));
+        match (name.to_ascii_lowercase().as_str(), param) {
+            ("u-mapper", "") => Ok(Self::UMapper),
+            ("serpentine", rows) => match rows.parse() {
+                Ok(rows) if rows > 0 => Ok(Self::Serpentine(rows)),
+                _ => Err(format!(
+                    "{s:?}: serpentine needs a row count, e.g. Serpentine:2"
+                )),
+            },
+            ("rotate", angle) => match angle.parse::<u32>() {
+                Ok(angle) if angle % 90 == 0 => Ok(Self::Rotate(angle % 360)),
+                _ => Err(format!("{s:?}: rotation must be a multiple of 90")),
+            },
+            ("mirror", "H" | "h") => Ok(Self::Mirror(true)),
+            ("mirror", "V" | "v") => Ok(Self::Mirror(false)),
+            _ => Err(format!(
+                "{s:?}: expected U-mapper, Serpentine:N, Rotate:DEG or Mirror:H|V"
+            )),
+        }
+    }
+}
+
+impl TryFrom<String> for PixelMapper {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl fmt::Display for PixelMapper {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UMapper => write!(f, "U-mapper"),
+            Self::Serpentine(rows) => write!(f, "Serpentine:{rows}"),
+            Self::Rotate(angle) => write!(f, "Rotate:{angle}"),
+            Self::Mirror(true) => write!(f, "Mirror:H"),
+            Self::Mirror(false) => write!(f, "Mirror:V"),
+        }
+    }
+}
+
+impl MatrixConfig {
+    /// Size of the chain as the hardware addresses it: every panel of
+    /// an output in one row, outputs stacked.
+    #[must_use]
+    pub fn chain_size(&self) -> (u32, u32) {
+        (self.cols * self.chain_length, self.rows * self.parallel)
+    }
+
+    /// Size of the canvas renderers draw on, after every mapper.
+    #[must_use]
+    pub fn canvas_size(&self) -> (u32, u32) {
+        self.pixel_mapper
+            .iter()
+            .fold(self.chain_size(), |size, mapper| mapper.apply(size))
+    }
+
+    /// Check the mappers can fold this chain: every fold has to split
+    /// the canvas it's given on a panel boundary, and come before any
+    /// quarter turn (folds run along the chain, which a quarter turn
+    /// stands on end).
+    pub fn validate(&self) -> Result<(), String> {
+        if self.rows == 0 || self.cols == 0 || self.chain_length == 0 || self.parallel == 0 {
+            return Err("matrix rows, cols, chain_length and parallel must be non-zero".to_owned());
+        }
+        let mut size = self.chain_size();
+        let mut quarter_turns = 0;
+        for mapper in &self.pixel_mapper {
+            let folds = match *mapper {
+                PixelMapper::UMapper => 2,
+                PixelMapper::Serpentine(rows) => rows,
+                PixelMapper::Rotate(angle) => {
+                    quarter_turns += angle / 90;
+                    1
+                }
+                PixelMapper::Mirror(_) => 1,
+            };
+            if folds > 1 {
+                if quarter_turns % 2 == 1 {
+                    return Err(format!(
+                        "pixel mapper {mapper} has to come before a 90° or 270° rotation"
+                    ));
+                }
+                let panels = size.0 / self.cols;
+                if !panels.is_multiple_of(folds) {
+                    return Err(format!(
+                        "pixel mapper {mapper} can't fold a row of {panels} panels"
+                    ));
+                }
+            }
+            size = mapper.apply(size);
+        }
+        Ok(())
+    }
+
+    /// Where canvas pixel `(x, y)` sits on the chain. Walks the
+    /// mappers backwards, undoing each one.
+    #[must_use]
+    pub fn to_physical(&self, x: u32, y: u32) -> (u32, u32) {
+        // Canvas size going into each mapper, physical end first.
+        let sizes = self
+            .pixel_mapper
+            .iter()
+            .scan(self.chain_size(), |size, mapper| {
+                let before = *size;
+                *size = mapper.apply(before);
+                Some(before)
+            });
+        let sizes: Vec<_> = sizes.collect();
+        self.pixel_mapper
+            .iter()
+            .zip(sizes)
+            .rev()
+            .fold((x, y), |(x, y), (mapper, (w, h))| match *mapper {
+                PixelMapper::UMapper => unfold(x, y, w, h / self.parallel, 2),
+                PixelMapper::Serpentine(rows) => unfold(x, y, w, h / self.parallel, rows),
+                PixelMapper::Rotate(90) => (w - 1 - y, x),
+                PixelMapper::Rotate(180) => (w - 1 - x, h - 1 - y),
+                PixelMapper::Rotate(270) => (y, h - 1 - x),
+                PixelMapper::Rotate(_) => (x, y),
+                PixelMapper::Mirror(true) => (w - 1 - x, y),
+                PixelMapper::Mirror(false) => (x, h - 1 - y),
+            })
+    }
+}
+
+impl PixelMapper {
+    /// Canvas size after this mapper, from the size before it.
+    fn apply(self, (w, h): (u32, u32)) -> (u32, u32) {
+        match self {
+            Self::UMapper => (w / 2, h * 2),
+            Self::Serpentine(rows) => (w / rows, h * rows),
+            Self::Rotate(90 | 270) => (h, w),
+            Self::Rotate(_) | Self::Mirror(_) => (w, h),
+        }
+    }
+}
+
+/// Undo a fold of a strip `width` wide into `rows` rows, each output's
+/// `strip_h`-tall strip folding separately. The chain starts top-left
+/// and the return rows run back upside down.
+fn unfold(x: u32, y: u32, width: u32, strip_h: u32, rows: u32) -> (u32, u32) {
+    let row_w = width / rows;
+    let slab_h = strip_h * rows;
+    let (output, y) = (y / slab_h, y % slab_h);
+    let (row, y) = (y / strip_h, y % strip_h);
+    // Rows wired right to left are the upside-down ones.
+    let (x, y) = if row % 2 == 1 {
+        (row_w - 1 - x, strip_h - 1 - y)
+    } else {
+        (x, y)
+    };
+    (row * row_w + x, output * strip_h + y)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    /// Four 64×32 panels on one chain.
+    fn four_panels(pixel_mapper: &[&str]) -> MatrixConfig {
+        MatrixConfig {
+            rows: 32,
+            cols: 64,
+            chain_length: 4,
+            parallel: 1,
+            pixel_mapper: pixel_mapper.iter().map(|m| m.parse().unwrap()).collect(),
+        }
+    }
+
+    #[test]
+    fn u_mapper_starts_top_left_and_returns_upside_down() {
+        let config = four_panels(&["U-mapper"]);
+        assert_eq!(config.canvas_size(), (128, 64));
+        // First panel top-left, running right along the top half.
+        assert_eq!(config.to_physical(0, 0), (0, 0));
+        assert_eq!(config.to_physical(127, 0), (127, 0));
+        assert_eq!(config.to_physical(127, 31), (127, 31));
+        // Then back, upside down, along the bottom half.
+        assert_eq!(config.to_physical(127, 63), (128, 0));
+        assert_eq!(config.to_physical(0, 63), (255, 0));
+        assert_eq!(config.to_physical(0, 32), (255, 31));
+    }
+
+    #[test]
+    fn serpentine_snakes_from_the_top_left() {
+        let config = MatrixConfig {
+            chain_length: 6,
+            ..four_panels(&["Serpentine:3"])
+        };
+        assert_eq!(config.canvas_size(), (128, 96));
+        assert_eq!(config.to_physical(0, 0), (0, 0));
+        assert_eq!(config.to_physical(127, 32), (128, 31));
+        assert_eq!(config.to_physical(0, 64), (256, 0));
+    }
+
+    #[test]
+    fn every_mapping_is_a_bijection() {
+        let configs = [
+            four_panels(&[]),
+            four_panels(&["U-mapper"]),
+            four_panels(&["Serpentine:4"]),
+            four_panels(&["U-mapper", "U-mapper"]),
+            four_panels(&["U-mapper", "Rotate:90"]),
+            four_panels(&["U-mapper", "Rotate:180", "Mirror:H"]),
+            four_panels(&["Mirror:V", "Serpentine:2", "Rotate:270"]),
+            MatrixConfig {
+                parallel: 2,
+                ..four_panels(&["U-mapper", "Rotate:90"])
+            },
+            MatrixConfig {
+                chain_length: 6,
+                parallel: 3,
+                ..four_panels(&["Serpentine:3", "Mirror:H"])
+            },
+        ];
+        for config in configs {
+            config.validate().unwrap();
+            let (w, h) = config.canvas_size();
+            let (chain_w, chain_h) = config.chain_size();
+            assert_eq!(w * h, chain_w * chain_h, "{config:?}");
+            let mut seen = HashSet::new();
+            for y in 0..h {
+                for x in 0..w {
+                    let (px, py) = config.to_physical(x, y);
+                    assert!(
+                        px < chain_w && py < chain_h,
+                        "{config:?}: ({x}, {y}) off the chain"
+                    );
+                    assert!(
+                        seen.insert((px, py)),
+                        "{config:?}: ({px}, {py}) mapped twice"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn validate_checks_each_fold_against_the_canvas_so_far() {
+        assert!(four_panels(&["U-mapper", "U-mapper"]).validate().is_ok());
+        assert!(four_panels(&["U-mapper", "U-mapper", "U-mapper"])
+            .validate()
+            .is_err());
+        assert!(four_panels(&["Serpentine:3"]).validate().is_err());
+        assert!(four_panels(&["Rotate:90", "U-mapper"]).validate().is_err());
+        assert!(four_panels(&["Rotate:180", "U-mapper"]).validate().is_ok());
+        assert!(four_panels(&["Rotate:90", "Rotate:270", "U-mapper"])
+            .validate()
+            .is_ok());
+    }
+}
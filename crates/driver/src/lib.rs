@@ -6,9 +6,17 @@
 /// startup via `panels.driver_version`.
 pub const DRIVER_VERSION: &str = env!("LED_DRIVER_VERSION");
 
+pub mod ambient;
+pub mod api;
+pub mod cache;
 pub mod config;
+pub mod data;
 pub mod display;
+pub mod geometry;
 pub mod realtime;
+pub mod schedule;
 pub mod sink;
+pub mod source;
 pub mod state;
 pub mod telemetry;
+pub mod weather;
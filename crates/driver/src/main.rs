@@ -9,11 +9,15 @@ use parking_lot::RwLock;
 use tokio::task::JoinSet;
 
 use led_driver::{
-    config,
-    display::drive,
+    ambient, api, cache,
+    config::{self, SourceConfig},
+    geometry::MatrixConfig,
+    data::{self, DataValues},
+    display::{self, drive},
     sink::{MatrixSink, TerminalMatrixSink},
+    source::{FileSource, SupabaseSource, WebSocketSource},
     state::{self, State},
-    telemetry,
+    telemetry, weather,
 };
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, Layer};
 
@@ -80,24 +84,61 @@ async fn main() -> anyhow::Result<()> {
         .init();
 
     tracing::info!("Setting up configuration...");
-    let sink = build_sink(args.terminal, config.color_order.as_deref())?;
+    let sink = build_sink(args.terminal, &config.matrix, config.color_order.as_deref())?;
+    if let Some(dir) = config.font_dir.as_deref().filter(|d| !d.as_os_str().is_empty()) {
+        display::load_fonts(dir);
+    }
+    display_core::font::set_fallbacks(config.fallback_fonts.clone());
 
     tracing::info!("Initializing state...");
-    let state = Arc::new(RwLock::new(State::default()));
+    let state_cache_path = config
+        .state_cache_path
+        .clone()
+        .filter(|p| !p.as_os_str().is_empty());
+    let initial_state = match state_cache_path
+        .as_deref()
+        .and_then(|path| cache::load(path, &config.id))
+    {
+        Some(cached) => {
+            tracing::info!(
+                mode = %cached.panel.mode,
+                last_updated = %cached.panel.last_updated,
+                "Resuming from cached state"
+            );
+            cached
+        }
+        None => State::default(),
+    };
+    let state = Arc::new(RwLock::new(initial_state));
 
     tracing::info!("Spawning tasks...");
     let mut tasks = JoinSet::new();
-    tasks.spawn(drive(sink, state.clone(), metrics.clone()));
-    tasks.spawn(async move {
-        state::sync(
-            config.id,
-            config.supabase_url,
-            config.supabase_anon_key,
-            state,
-            metrics,
-        )
-        .await
-    });
+    let data_values = DataValues::default();
+    tasks.spawn(drive(
+        sink,
+        state.clone(),
+        data_values.clone(),
+        config.calibration,
+        metrics.clone(),
+    ));
+    if !config.data_sources.is_empty() {
+        tasks.spawn(data::poll(config.data_sources.clone(), data_values.clone()));
+    }
+    if let Some(weather) = config.weather.clone() {
+        tasks.spawn(weather::run(weather, data_values.clone()));
+    }
+    if let Some(ambient_light) = config.ambient_light.clone() {
+        tasks.spawn(ambient::run(ambient_light, data_values));
+    }
+    if let Some(addr) = config.local_api_addr {
+        tasks.spawn(api::serve(
+            addr,
+            state.clone(),
+            config.id.clone(),
+            state_cache_path.clone(),
+        ));
+    }
+    spawn_sync(&mut tasks, config, state_cache_path, state, metrics);
 
     tracing::info!("Waiting for tasks...");
     while let Some(result) = tasks.join_next().await {
@@ -107,13 +148,58 @@ async fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Spawn the state sync loop against whichever backend `[source]`
+/// selects.
+fn spawn_sync(
+    tasks: &mut JoinSet<anyhow::Result<()>>,
+    config: config::Config,
+    state_cache_path: Option<PathBuf>,
+    state: Arc<RwLock<State>>,
+    metrics: Arc<telemetry::Metrics>,
+) {
+    let panel_name = config.id.clone();
+    match config.source {
+        SourceConfig::Supabase => {
+            let source =
+                SupabaseSource::new(config.id, config.supabase_url, config.supabase_anon_key);
+            tasks.spawn(state::sync(
+                Arc::new(source),
+                panel_name,
+                state_cache_path,
+                state,
+                metrics,
+            ));
+        }
+        SourceConfig::File { path } => {
+            tasks.spawn(state::sync(
+                Arc::new(FileSource::new(path)),
+                panel_name,
+                state_cache_path,
+                state,
+                metrics,
+            ));
+        }
+        SourceConfig::Websocket { url } => {
+            tasks.spawn(state::sync(
+                Arc::new(WebSocketSource::new(url, config.id)),
+                panel_name,
+                state_cache_path,
+                state,
+                metrics,
+            ));
+        }
+    }
+}
+
 #[cfg(feature = "rpi")]
 fn build_sink(
     terminal: bool,
+    matrix: &MatrixConfig,
     color_order: Option<&str>,
 ) -> anyhow::Result<Box<dyn MatrixSink>> {
     if terminal {
-        return Ok(Box::new(TerminalMatrixSink::new(64, 64, 30.0)));
+        let (width, height) = matrix.canvas_size();
+        return Ok(Box::new(TerminalMatrixSink::new(width, height, 30.0)));
     }
     use led_driver::sink::RpiMatrixSink;
     use rpi_led_panel::{LedSequence, RGBMatrixConfig};
@@ -137,17 +223,19 @@ fn build_sink(
         led_sequence,
         ..Default::default()
     };
-    Ok(Box::new(RpiMatrixSink::new(matrix_config)?))
+    Ok(Box::new(RpiMatrixSink::new(matrix_config, matrix)?))
 }
 
 #[cfg(not(feature = "rpi"))]
 #[allow(clippy::unnecessary_wraps)] // mirror the `rpi` branch's signature
 fn build_sink(
     _terminal: bool,
+    matrix: &MatrixConfig,
     _color_order: Option<&str>,
 ) -> anyhow::Result<Box<dyn MatrixSink>> {
     // Built without the `rpi` feature — terminal sink is the only
     // option. `--terminal` is implied; the flag is accepted but a
     // no-op so call sites stay uniform.
-    Ok(Box::new(TerminalMatrixSink::new(64, 64, 30.0)))
+    let (width, height) = matrix.canvas_size();
+    Ok(Box::new(TerminalMatrixSink::new(width, height, 30.0)))
 }
@@ -0,0 +1,234 @@
+//! Time-of-day scheduler.
+//!
+//! `panels.schedule` holds a list of weekly windows. While a window
+//! is active its overrides (off/on, brightness, mode) replace the
+//! panel's own settings; outside every window the panel renders as
+//! configured. Evaluated by the render loop each frame against the
+//! schedule's timezone, so it keeps working with the network down.
+
+use std::cmp::Ordering;
+
+use chrono::{Datelike, NaiveDateTime, Timelike, Weekday};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+/// Persisted shape of `panels.schedule`.
+#[derive(PartialEq, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Schedule {
+    /// IANA timezone the windows are written in; `None` falls back to
+    /// the Pi's system local time.
+    #[serde(default)]
+    pub timezone: Option<String>,
+    /// Checked in order; the first window containing "now" wins.
+    #[serde(default)]
+    pub windows: Vec<ScheduleWindow>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Day {
+    Mon,
+    Tue,
+    Wed,
+    Thu,
+    Fri,
+    Sat,
+    Sun,
+}
+
+impl From<Weekday> for Day {
+    fn from(day: Weekday) -> Self {
+        match day {
+            Weekday::Mon => Self::Mon,
+            Weekday::Tue => Self::Tue,
+            Weekday::Wed => Self::Wed,
+            Weekday::Thu => Self::Thu,
+            Weekday::Fri => Self::Fri,
+            Weekday::Sat => Self::Sat,
+            Weekday::Sun => Self::Sun,
+        }
+    }
+}
+
+/// One weekly window plus what to override while it's active.
+// Not `Eq`: `brightness` is an f32.
+#[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+pub struct ScheduleWindow {
+    /// Days the window starts on. Empty = every day.
+    #[serde(default)]
+    pub days: Vec<Day>,
+    /// `"HH:MM"`, inclusive.
+    pub start: String,
+    /// `"HH:MM"`, exclusive. At or before `start` the window runs past
+    /// midnight into the next day; equal to `start` covers the whole
+    /// day.
+    pub end: String,
+    /// Force the panel off (`true`) or on (`false`).
+    #[serde(default)]
+    pub is_off: Option<bool>,
+    /// Brightness multiplier in [0, 1].
+    #[serde(default)]
+    pub brightness: Option<f32>,
+    /// Render mode to switch to, as in `panels.mode`.
+    #[serde(default)]
+    pub mode: Option<String>,
+    /// Config for `mode`. Missing = that mode's defaults.
+    #[serde(default)]
+    pub mode_config: Option<JsonValue>,
+}
+
+impl Schedule {
+    /// The first window active at `now`, with its index. `now` is
+    /// local time in [`Schedule::timezone`].
+    #[must_use]
+    pub fn active(&self, now: NaiveDateTime) -> Option<(usize, &ScheduleWindow)> {
+        self.windows
+            .iter()
+            .enumerate()
+            .find(|(_, window)| window.contains(now))
+    }
+}
+
+impl ScheduleWindow {
+    /// Whether `now` falls inside the window. Windows with an
+    /// unparseable `start` / `end` never match.
+    #[must_use]
+    pub fn contains(&self, now: NaiveDateTime) -> bool {
+        let (Some(start), Some(end)) = (parse_minutes(&self.start), parse_minutes(&self.end))
+        else {
+            return false;
+        };
+        let minute = now.hour() * 60 + now.minute();
+        let today = Day::from(now.weekday());
+        let yesterday = Day::from(now.weekday().pred());
+        match start.cmp(&end) {
+            Ordering::Less => self.on(today) && (start..end).contains(&minute),
+            Ordering::Equal => self.on(today),
+            // Wraps midnight: the evening half belongs to today, the
+            // early-morning half to the window that started yesterday.
+            Ordering::Greater => {
+                (self.on(today) && minute >= start) || (self.on(yesterday) && minute < end)
+            }
+        }
+    }
+
+    fn on(&self, day: Day) -> bool {
+        self.days.is_empty() || self.days.contains(&day)
+    }
+}
+
+/// `"HH:MM"` → minutes since midnight.
+fn parse_minutes(hm: &str) -> Option<u32> {
+    let (h, m) = hm.trim().split_once(':')?;
+    let (h, m) = (h.parse::<u32>().ok()?, m.parse::<u32>().ok()?);
+    (h < 24 && m < 60).then_some(h * 60 + m)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    /// `HH:MM` on the given day of the week of 2026-06-01 (a Monday).
+    fn at(day: Weekday, hm: &str) -> NaiveDateTime {
+        let minutes = parse_minutes(hm).unwrap();
+        NaiveDate::from_ymd_opt(2026, 6, 1 + day.num_days_from_monday())
+            .unwrap()
+            .and_hms_opt(minutes / 60, minutes % 60, 0)
+            .unwrap()
+    }
+
+    fn window(days: &[Day], start: &str, end: &str) -> ScheduleWindow {
+        ScheduleWindow {
+            days: days.to_vec(),
+            start: start.to_owned(),
+            end: end.to_owned(),
+            is_off: None,
+            brightness: None,
+            mode: None,
+            mode_config: None,
+        }
+    }
+
+    #[test]
+    fn same_day_window_is_start_inclusive_end_exclusive() {
+        let daytime = window(&[], "09:00", "17:00");
+        assert!(!daytime.contains(at(Weekday::Mon, "08:59")));
+        assert!(daytime.contains(at(Weekday::Mon, "09:00")));
+        assert!(daytime.contains(at(Weekday::Mon, "16:59")));
+        assert!(!daytime.contains(at(Weekday::Mon, "17:00")));
+    }
+
+    #[test]
+    fn window_wraps_past_midnight() {
+        let night = window(&[], "22:00", "06:00");
+        assert!(night.contains(at(Weekday::Mon, "22:00")));
+        assert!(night.contains(at(Weekday::Mon, "23:59")));
+        assert!(night.contains(at(Weekday::Tue, "00:00")));
+        assert!(night.contains(at(Weekday::Tue, "05:59")));
+        assert!(!night.contains(at(Weekday::Tue, "06:00")));
+        assert!(!night.contains(at(Weekday::Tue, "21:59")));
+    }
+
+    #[test]
+    fn equal_start_and_end_covers_the_whole_day() {
+        let all_day = window(&[Day::Sat], "07:00", "07:00");
+        assert!(all_day.contains(at(Weekday::Sat, "00:00")));
+        assert!(all_day.contains(at(Weekday::Sat, "23:59")));
+        assert!(!all_day.contains(at(Weekday::Sun, "00:00")));
+        assert!(!all_day.contains(at(Weekday::Fri, "23:59")));
+    }
+
+    #[test]
+    fn days_filter_on_the_day_a_window_starts() {
+        let weekdays = window(&[Day::Mon, Day::Tue], "09:00", "17:00");
+        assert!(weekdays.contains(at(Weekday::Tue, "12:00")));
+        assert!(!weekdays.contains(at(Weekday::Wed, "12:00")));
+        // A Friday night window's tail runs into Saturday morning, but
+        // Saturday night isn't in it.
+        let friday_night = window(&[Day::Fri], "22:00", "06:00");
+        assert!(friday_night.contains(at(Weekday::Fri, "23:00")));
+        assert!(friday_night.contains(at(Weekday::Sat, "05:00")));
+        assert!(!friday_night.contains(at(Weekday::Sat, "23:00")));
+        assert!(!friday_night.contains(at(Weekday::Fri, "05:00")));
+    }
+
+    #[test]
+    fn parse_minutes_rejects_malformed_times() {
+        assert_eq!(parse_minutes("00:00"), Some(0));
+        assert_eq!(parse_minutes(" 23:59 "), Some(23 * 60 + 59));
+        assert_eq!(parse_minutes("7:05"), Some(7 * 60 + 5));
+        for bad in [
+            "24:00", "12:60", "-1:00", "12", "12:", ":30", "ab:cd", "", "12:30:00",
+        ] {
+            assert_eq!(parse_minutes(bad), None, "{bad:?}");
+        }
+        assert!(!window(&[], "25:00", "06:00").contains(at(Weekday::Mon, "05:00")));
+    }
+
+    #[test]
+    fn first_matching_window_wins() {
+        let schedule = Schedule {
+            timezone: None,
+            windows: vec![
+                window(&[Day::Sun], "00:00", "00:00"),
+                ScheduleWindow {
+                    is_off: Some(true),
+                    ..window(&[], "22:00", "07:00")
+                },
+                ScheduleWindow {
+                    brightness: Some(0.2),
+                    ..window(&[], "20:00", "23:00")
+                },
+            ],
+        };
+        assert_eq!(schedule.active(at(Weekday::Mon, "12:00")), None);
+        let (index, _) = schedule.active(at(Weekday::Mon, "21:00")).unwrap();
+        assert_eq!(index, 2);
+        let (index, active) = schedule.active(at(Weekday::Mon, "22:30")).unwrap();
+        assert_eq!((index, active.is_off), (1, Some(true)));
+        let (index, _) = schedule.active(at(Weekday::Sun, "22:30")).unwrap();
+        assert_eq!(index, 0);
+    }
+}
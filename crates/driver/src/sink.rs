@@ -18,6 +18,7 @@ use std::fmt::Write as _;
 use std::io::{self, Write};
 use std::time::{Duration, Instant};
 
+use display_core::calibration::CalibrationLut;
 use embedded_graphics::{
     pixelcolor::Rgb888,
     prelude::*,
@@ -59,6 +60,13 @@ impl PixelBuffer {
     pub fn pixel(&self, x: u32, y: u32) -> Rgb888 {
         self.pixels[(y * self.width + x) as usize]
     }
+
+    /// Run every pixel through `lut`, once the frame is finished.
+    pub fn calibrate(&mut self, lut: &CalibrationLut) {
+        for p in &mut self.pixels {
+            *p = lut.apply(*p);
+        }
+    }
 }
 
 impl DrawTarget for PixelBuffer {
@@ -104,6 +112,7 @@ pub trait MatrixSink: Send {
 #[cfg(feature = "rpi")]
 mod rpi {
     use super::{MatrixSink, PixelBuffer};
+    use crate::geometry::MatrixConfig;
     use anyhow::Context;
     use embedded_graphics::prelude::RgbColor;
     use rpi_led_panel::{Canvas, RGBMatrix, RGBMatrixConfig};
@@ -112,23 +121,46 @@ mod rpi {
     pub struct RpiMatrixSink {
         matrix: RGBMatrix,
         canvas: Option<Box<Canvas>>,
+        /// Virtual canvas size, per `[matrix]`.
+        size: (u32, u32),
+        /// Chain coordinates of each virtual pixel, row-major —
+        /// `[matrix]` mapping worked out once instead of per frame.
+        physical: Vec<(usize, usize)>,
     }
 
     impl RpiMatrixSink {
-        pub fn new(config: RGBMatrixConfig) -> anyhow::Result<Self> {
+        /// `config` carries the hardware knobs; its size and chaining
+        /// are overwritten from `geometry`.
+        pub fn new(config: RGBMatrixConfig, geometry: &MatrixConfig) -> anyhow::Result<Self> {
+            let config = RGBMatrixConfig {
+                rows: geometry.rows as usize,
+                cols: geometry.cols as usize,
+                chain_length: geometry.chain_length as usize,
+                parallel: geometry.parallel as usize,
+                ..config
+            };
             let (matrix, canvas) =
                 RGBMatrix::new(config, 0).context("Matrix initialization failed")?;
+            let (width, height) = geometry.canvas_size();
+            let physical = (0..height)
+                .flat_map(|y| (0..width).map(move |x| (x, y)))
+                .map(|(x, y)| {
+                    let (x, y) = geometry.to_physical(x, y);
+                    (x as usize, y as usize)
+                })
+                .collect();
             Ok(Self {
                 matrix,
                 canvas: Some(canvas),
+                size: (width, height),
+                physical,
             })
         }
     }
 
     impl MatrixSink for RpiMatrixSink {
         fn dimensions(&self) -> (u32, u32) {
-            let canvas = self.canvas.as_ref().expect("canvas absent between frames");
-            (canvas.width() as u32, canvas.height() as u32)
+            self.size
         }
 
         fn present(&mut self, buffer: &PixelBuffer) -> anyhow::Result<()> {
@@ -141,7 +173,8 @@ mod rpi {
             for y in 0..h {
                 for x in 0..w {
                     let p = buffer.pixel(x, y);
-                    canvas.set_pixel(x as usize, y as usize, p.r(), p.g(), p.b());
+                    let (px, py) = self.physical[(y * w + x) as usize];
+                    canvas.set_pixel(px, py, p.r(), p.g(), p.b());
                 }
             }
             let next = block_in_place(|| self.matrix.update_on_vsync(canvas));
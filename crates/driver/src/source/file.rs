@@ -0,0 +1,186 @@
+//! File backend: panel state lives in a JSON file on disk, reloaded
+//! whenever inotify reports it was written or replaced.
+//!
+//! The file holds a serialized [`State`] — `{ "panel": {...},
+//! "entries": [...] }` with the panel fields of a `panels` row. An
+//! explicit `panel.last_updated` is honoured; otherwise the file's
+//! mtime stands in for it so the render loop's config cache still
+//! invalidates on every edit.
+//!
+//! We watch the parent directory rather than the file itself so
+//! editors and `mv`-into-place deploys (which swap the inode) keep
+//! triggering reloads.
+
+use std::path::PathBuf;
+use std::time::UNIX_EPOCH;
+
+use anyhow::Context;
+use futures_util::StreamExt;
+use inotify::{Inotify, WatchMask};
+use tokio::sync::mpsc;
+
+use super::StateSource;
+use crate::state::State;
+
+pub struct FileSource {
+    path: PathBuf,
+}
+
+impl FileSource {
+    #[must_use]
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+impl StateSource for FileSource {
+    /// Nothing to look up — the file *is* the panel. The id only has
+    /// to be stable and non-empty.
+    async fn resolve_panel(&self) -> anyhow::Result<String> {
+        Ok(format!("file:{}", self.path.display()))
+    }
+
+    async fn fetch(&self, panel_id: &str, last_updated: &str) -> anyhow::Result<Option<State>> {
+        let raw = tokio::fs::read_to_string(&self.path)
+            .await
+            .with_context(|| format!("read {}", self.path.display()))?;
+        let mut state: State = serde_json::from_str(&raw)
+            .with_context(|| format!("parse {}", self.path.display()))?;
+        if state.panel.last_updated.is_empty() {
+            let modified = tokio::fs::metadata(&self.path).await?.modified()?;
+            let since_epoch = modified.duration_since(UNIX_EPOCH).unwrap_or_default();
+            state.panel.last_updated = format!("mtime:{}", since_epoch.as_nanos());
+        }
+        if state.panel.last_updated == last_updated {
+            tracing::debug!("State file unchanged, skipping reload");
+            return Ok(None);
+        }
+        panel_id.clone_into(&mut state.panel.id);
+        tracing::info!("Loaded state file, got {} entries", state.entries.len());
+        Ok(Some(state))
+    }
+
+    async fn subscribe(&self, _panel_id: String, nudge_tx: mpsc::Sender<()>) -> anyhow::Result<()> {
+        let dir = self
+            .path
+            .parent()
+            .filter(|d| !d.as_os_str().is_empty())
+            .unwrap_or_else(|| std::path::Path::new("."));
+        let file_name = self
+            .path
+            .file_name()
+            .with_context(|| format!("state file {} has no file name", self.path.display()))?
+            .to_owned();
+
+        let inotify = Inotify::init().context("inotify init")?;
+        inotify
+            .watches()
+            .add(
+                dir,
+                WatchMask::CLOSE_WRITE | WatchMask::MOVED_TO | WatchMask::CREATE,
+            )
+            .with_context(|| format!("watch {}", dir.display()))?;
+        let mut events = inotify
+            .into_event_stream([0_u8; 4096])
+            .context("inotify event stream")?;
+        tracing::info!(path = %self.path.display(), "Watching state file");
+
+        // Initial load.
+        let _ = nudge_tx.try_send(());
+
+        while let Some(event) = events.next().await {
+            let event = event.context("inotify read")?;
+            if event.name.as_deref() == Some(file_name.as_os_str()) {
+                let _ = nudge_tx.try_send(());
+            }
+        }
+        anyhow::bail!("inotify stream ended")
+    }
+
+    /// Nobody to report liveness to.
+    async fn heartbeat(&self, _panel_id: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use parking_lot::RwLock;
+
+    use super::*;
+    use crate::telemetry::Metrics;
+
+    /// A fresh state file path under the system temp dir.
+    fn state_path(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("led-file-source-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir.join("state.json")
+    }
+
+    fn write_state(path: &std::path::Path, mode: &str) {
+        let mut state = State::default();
+        state.panel.name = "kitchen".to_owned();
+        state.panel.mode = mode.to_owned();
+        state.panel.mode_config = serde_json::json!({});
+        std::fs::write(path, serde_json::to_string(&state).unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn fetch_parses_the_file_and_skips_it_unchanged() {
+        let path = state_path("fetch");
+        write_state(&path, "clock");
+        let source = FileSource::new(path.clone());
+        let state = source.fetch("file:test", "").await.unwrap().unwrap();
+        assert_eq!(state.panel.name, "kitchen");
+        assert_eq!(state.panel.mode, "clock");
+        assert_eq!(state.panel.id, "file:test");
+        assert!(state.panel.last_updated.starts_with("mtime:"));
+        let unchanged = source.fetch("file:test", &state.panel.last_updated).await;
+        assert_eq!(unchanged.unwrap(), None);
+
+        std::fs::write(&path, r#"{"panel": {"name": "kitchen"#).unwrap();
+        assert!(source.fetch("file:test", "").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn sync_follows_edits_and_keeps_state_through_a_bad_one() {
+        let path = state_path("sync");
+        write_state(&path, "clock");
+        let state = Arc::new(RwLock::new(State::default()));
+        let metrics = Arc::new(Metrics::new(&opentelemetry::global::meter("test")));
+        tokio::spawn(crate::state::sync(
+            Arc::new(FileSource::new(path.clone())),
+            "kitchen".to_owned(),
+            None,
+            state.clone(),
+            metrics,
+        ));
+        let mode_becomes = |mode: &'static str| {
+            let state = state.clone();
+            async move {
+                for _ in 0..100 {
+                    if state.read().panel.mode == mode {
+                        return true;
+                    }
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                }
+                false
+            }
+        };
+        assert!(mode_becomes("clock").await);
+        let loaded = state.read().clone();
+
+        std::fs::write(&path, "{ not json").unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert_eq!(*state.read(), loaded);
+
+        // Still watching after the bad write.
+        write_state(&path, "life");
+        assert!(mode_becomes("life").await);
+    }
+}
@@ -0,0 +1,62 @@
+//! Pluggable backends for panel state.
+//!
+//! [`crate::state::sync`] drives any [`StateSource`]: resolve our
+//! panel once, then re-fetch whenever the source's change
+//! subscription nudges, with a periodic heartbeat alongside. Which
+//! implementation runs is picked by `[source]` in `config.toml`:
+//!
+//! * [`SupabaseSource`] — REST pulls + Realtime nudges. Default.
+//! * [`FileSource`] — a JSON file on disk, reloaded on inotify events.
+//! * [`WebSocketSource`] — full state snapshots pushed over a plain
+//!   WebSocket by whatever server the install runs.
+
+use std::future::Future;
+
+use tokio::sync::mpsc;
+
+use crate::state::State;
+
+pub mod file;
+pub mod supabase;
+pub mod websocket;
+
+pub use file::FileSource;
+pub use supabase::SupabaseSource;
+pub use websocket::WebSocketSource;
+
+/// A backend the sync loop can pull panel state from.
+///
+/// Methods return `Send` futures so the sync loop can spawn the
+/// subscriber and heartbeat onto the runtime.
+pub trait StateSource: Send + Sync + 'static {
+    /// Resolve our panel's id, creating the panel if the backend
+    /// supports that. One attempt; the sync loop owns retry/timeout.
+    fn resolve_panel(&self) -> impl Future<Output = anyhow::Result<String>> + Send;
+
+    /// Record the running driver version against the panel. Best
+    /// effort; backends with nowhere to put it keep the default no-op.
+    fn report_version(&self, _panel_id: &str) -> impl Future<Output = anyhow::Result<()>> + Send {
+        async { Ok(()) }
+    }
+
+    /// Fetch the current state. `Ok(None)` when the backend's
+    /// `last_updated` still matches the one we hold.
+    fn fetch(
+        &self,
+        panel_id: &str,
+        last_updated: &str,
+    ) -> impl Future<Output = anyhow::Result<Option<State>>> + Send;
+
+    /// Run forever, sending `()` on `nudge_tx` whenever the state may
+    /// have changed — including once up front (and after any
+    /// reconnect) so the first fetch happens without waiting for an
+    /// edit. Returns only on unrecoverable errors.
+    fn subscribe(
+        &self,
+        panel_id: String,
+        nudge_tx: mpsc::Sender<()>,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    /// Liveness ping, called every heartbeat period.
+    fn heartbeat(&self, panel_id: &str) -> impl Future<Output = anyhow::Result<()>> + Send;
+}
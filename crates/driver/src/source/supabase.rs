@@ -0,0 +1,231 @@
+//! Supabase backend: the REST API for reads/writes, Realtime for change
+//! nudges (see [`crate::realtime`]).
+
+use postgrest::Postgrest;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+
+use super::StateSource;
+use crate::display::{Panel, TextEntry};
+use crate::state::State;
+
+#[derive(Deserialize, Serialize)]
+struct TextEntryResponse {
+    data: TextEntry,
+}
+
+pub struct SupabaseSource {
+    panel_name: String,
+    supabase_url: String,
+    supabase_anon_key: String,
+    client: Postgrest,
+}
+
+impl SupabaseSource {
+    #[must_use]
+    pub fn new(panel_name: String, supabase_url: String, supabase_anon_key: String) -> Self {
+        let postgrest_url = format!("{}/rest/v1", supabase_url.trim_end_matches('/'));
+        let client = Postgrest::new(&postgrest_url).insert_header("apikey", &supabase_anon_key);
+        Self {
+            panel_name,
+            supabase_url,
+            supabase_anon_key,
+            client,
+        }
+    }
+}
+
+impl StateSource for SupabaseSource {
+    async fn resolve_panel(&self) -> anyhow::Result<String> {
+        let panel_name = self.panel_name.as_str();
+        let client = &self.client;
+        tracing::debug!("Getting panel ID for name {}...", panel_name);
+        let panels: Vec<Panel> = serde_json::from_str(
+            &client
+                .from("panels")
+                .select("*")
+                .eq("name", panel_name)
+                .execute()
+                .await?
+                .text()
+                .await?,
+        )?;
+
+        match panels.len() {
+            0 => {
+                tracing::warn!("Panel not found, creating...");
+                let new_panel = new_panel(panel_name);
+                let response = client
+                    .from("panels")
+                    .insert(serde_json::to_string(&new_panel)?)
+                    .execute()
+                    .await?;
+                if !response.status().is_success() {
+                    let status = response.status();
+                    let text = response.text().await.unwrap_or_default();
+                    anyhow::bail!("panel insert returned {status}: {text}");
+                }
+                // Re-select by name; the insert response shape is `Prefer`-dependent.
+                let _ = response.text().await;
+                let panels: Vec<Panel> = serde_json::from_str(
+                    &client
+                        .from("panels")
+                        .select("*")
+                        .eq("name", panel_name)
+                        .execute()
+                        .await?
+                        .text()
+                        .await?,
+                )?;
+                panels
+                    .into_iter()
+                    .next()
+                    .map(|p| p.id)
+                    .ok_or_else(|| anyhow::anyhow!("freshly-created panel disappeared on read-back"))
+            }
+            1 => {
+                tracing::debug!("Panel found with ID {}", panels[0].id);
+                Ok(panels[0].id.clone())
+            }
+            _ => Err(anyhow::anyhow!(
+                "Multiple panels found with name {}",
+                panel_name
+            )),
+        }
+    }
+
+    /// Stamp our build version on the panel row so the dash can flag
+    /// Pis running an older binary than the rest of the fleet.
+    async fn report_version(&self, panel_id: &str) -> anyhow::Result<()> {
+        tracing::info!(version = crate::DRIVER_VERSION, "Reporting driver version");
+        let body = serde_json::json!({ "driver_version": crate::DRIVER_VERSION }).to_string();
+        let response = self
+            .client
+            .from("panels")
+            .eq("id", panel_id)
+            .update(body)
+            .execute()
+            .await?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("driver_version update returned {status}: {text}");
+        }
+        Ok(())
+    }
+
+    async fn fetch(&self, panel_id: &str, last_updated: &str) -> anyhow::Result<Option<State>> {
+        let client = &self.client;
+        tracing::debug!("Downloading state...");
+        let now = Instant::now();
+        tracing::debug!("Downloading panel information...");
+        let panels: Vec<Panel> = serde_json::from_str(
+            &client
+                .from("panels")
+                .select("*")
+                .eq("id", panel_id)
+                .execute()
+                .await?
+                .text()
+                .await?,
+        )?;
+        let panel = panels
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("No panel found"))?;
+
+        if panel.last_updated == last_updated {
+            tracing::debug!("State is up to date, skipping download");
+            return Ok(None);
+        }
+
+        tracing::debug!("Downloading text entries...");
+        let entries: Vec<TextEntry> = serde_json::from_str::<Vec<TextEntryResponse>>(
+            &client
+                .from("entries")
+                .select("*")
+                .eq("panel_id", panel_id)
+                .order("order.asc")
+                .execute()
+                .await?
+                .text()
+                .await?,
+        )?
+        .into_iter()
+        .map(|x| x.data)
+        .collect();
+
+        tracing::info!("Downloaded state, got {} entries", entries.len());
+        tracing::debug!("Downloaded state in {:?}", now.elapsed());
+        Ok(Some(State { panel, entries }))
+    }
+
+    /// Realtime subscriber: each `postgres_changes` event for our panel
+    /// pushes a nudge. The subscriber also nudges on every fresh
+    /// connection (initial startup + reconnect after drop), so we
+    /// always do a full pull when the channel comes up.
+    async fn subscribe(&self, panel_id: String, nudge_tx: mpsc::Sender<()>) -> anyhow::Result<()> {
+        crate::realtime::run(
+            self.supabase_url.clone(),
+            self.supabase_anon_key.clone(),
+            panel_id,
+            nudge_tx,
+        )
+        .await
+    }
+
+    /// Write `panels.last_seen` (dash liveness). The dash marks panels
+    /// offline when `last_seen` is stale — independent of `last_updated`,
+    /// which only moves when entry data changes.
+    async fn heartbeat(&self, panel_id: &str) -> anyhow::Result<()> {
+        let body = serde_json::json!({ "last_seen": chrono::Utc::now().to_rfc3339() }).to_string();
+        let response = self
+            .client
+            .from("panels")
+            .eq("id", panel_id)
+            .update(body)
+            .execute()
+            .await?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("last_seen update returned {status}: {text}");
+        }
+        Ok(())
+    }
+}
+
+/// The row to insert for a panel that doesn't exist yet.
+///
+/// `Default` for Panel leaves `mode = ""` (empty string), but the dash
+/// and dispatch logic both expect `"text"`. Fill it explicitly so
+/// freshly auto-created panels render text mode. The jsonb columns
+/// must be JSON objects too — they're `not null`, and the `Null` that
+/// `JsonValue` defaults to gets the insert rejected.
+fn new_panel(name: &str) -> Panel {
+    Panel {
+        name: name.to_string(),
+        mode: "text".to_string(),
+        mode_config: serde_json::json!({}),
+        calibration: serde_json::json!({}),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_panel_has_no_null_columns() {
+        // Every column the insert carries is `not null`.
+        let row = serde_json::to_value(new_panel("kitchen")).unwrap();
+        let columns = row.as_object().unwrap();
+        for (column, value) in columns {
+            assert!(!value.is_null(), "{column} is null");
+        }
+        assert_eq!(columns["name"], "kitchen");
+        assert_eq!(columns["mode"], "text");
+    }
+}
@@ -0,0 +1,152 @@
+//! Plain WebSocket backend for self-hosted control planes.
+//!
+//! The driver connects to `url`, sends one join frame naming its
+//! panel, and from then on every text frame the server pushes is a
+//! full [`State`] snapshot (`{ "panel": {...}, "entries": [...] }`).
+//! Unlike Supabase there's no separate pull: the subscriber keeps the
+//! latest snapshot and [`fetch`](StateSource::fetch) just hands it to
+//! the sync loop.
+//!
+//! Join frame: `{ "event": "join", "panel": "<config id>" }`. Servers
+//! that don't care about multi-panel routing can ignore it.
+//!
+//! Works equally for an MQTT broker's WebSocket listener fronted by a
+//! tiny bridge, a Home Assistant add-on, or a bespoke server — the
+//! only contract is "push the whole state as JSON".
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::Context;
+use futures_util::{SinkExt, StreamExt};
+use parking_lot::Mutex;
+use serde_json::json;
+use tokio::sync::mpsc;
+use tokio::time::{interval, sleep};
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+use super::StateSource;
+use crate::state::State;
+
+const PING_PERIOD: Duration = Duration::from_secs(30);
+const RECONNECT_BACKOFF: Duration = Duration::from_secs(5);
+
+pub struct WebSocketSource {
+    url: String,
+    panel_name: String,
+    /// Most recent snapshot pushed by the server.
+    latest: Mutex<Option<State>>,
+    /// Bumped per received snapshot; stands in for `last_updated`
+    /// when the server doesn't send one.
+    generation: AtomicU64,
+    /// When this process made the source, in nanoseconds. Prefixes the
+    /// stand-in `last_updated` so a restarted driver's first snapshot
+    /// can't match the one it cached before the restart.
+    started: u128,
+}
+
+impl WebSocketSource {
+    #[must_use]
+    pub fn new(url: String, panel_name: String) -> Self {
+        Self {
+            url,
+            panel_name,
+            latest: Mutex::new(None),
+            generation: AtomicU64::new(0),
+            started: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos(),
+        }
+    }
+
+    async fn connect_and_listen(&self, nudge_tx: &mpsc::Sender<()>) -> anyhow::Result<()> {
+        tracing::info!(url = %self.url, "Connecting to state WebSocket...");
+        let (mut ws, _resp) = connect_async(self.url.as_str())
+            .await
+            .context("state websocket connect")?;
+        let join = json!({ "event": "join", "panel": self.panel_name });
+        ws.send(Message::Text(join.to_string()))
+            .await
+            .context("send join")?;
+        tracing::info!("State WebSocket joined");
+
+        let mut ping = interval(PING_PERIOD);
+        ping.tick().await; // skip the immediate fire
+
+        loop {
+            tokio::select! {
+                _ = ping.tick() => {
+                    ws.send(Message::Ping(Vec::new())).await.context("send ping")?;
+                }
+                incoming = ws.next() => {
+                    let Some(msg) = incoming else { return Ok(()); };
+                    match msg.context("ws recv")? {
+                        Message::Text(text) => self.handle_snapshot(&text, nudge_tx),
+                        Message::Ping(payload) => {
+                            ws.send(Message::Pong(payload)).await.ok();
+                        }
+                        Message::Close(_) => return Ok(()),
+                        Message::Binary(_) | Message::Pong(_) | Message::Frame(_) => {}
+                    }
+                }
+            }
+        }
+    }
+
+    fn handle_snapshot(&self, text: &str, nudge_tx: &mpsc::Sender<()>) {
+        let mut state: State = match serde_json::from_str(text) {
+            Ok(state) => state,
+            Err(err) => {
+                tracing::warn!(error = %err, "State WebSocket: unparseable snapshot ignored");
+                return;
+            }
+        };
+        if state.panel.last_updated.is_empty() {
+            let n = self.generation.fetch_add(1, Ordering::Relaxed);
+            state.panel.last_updated = format!("ws:{}:{n}", self.started);
+        }
+        *self.latest.lock() = Some(state);
+        let _ = nudge_tx.try_send(());
+    }
+}
+
+impl StateSource for WebSocketSource {
+    /// The server addresses panels by name; that's our id too.
+    async fn resolve_panel(&self) -> anyhow::Result<String> {
+        Ok(self.panel_name.clone())
+    }
+
+    async fn fetch(&self, panel_id: &str, last_updated: &str) -> anyhow::Result<Option<State>> {
+        let latest = self.latest.lock();
+        let Some(state) = latest.as_ref() else {
+            tracing::debug!("No snapshot received yet");
+            return Ok(None);
+        };
+        if state.panel.last_updated == last_updated {
+            tracing::debug!("State is up to date, skipping");
+            return Ok(None);
+        }
+        let mut state = state.clone();
+        panel_id.clone_into(&mut state.panel.id);
+        tracing::info!("Received state, got {} entries", state.entries.len());
+        Ok(Some(state))
+    }
+
+    /// Reconnects forever; each pushed snapshot nudges the sync loop.
+    async fn subscribe(&self, _panel_id: String, nudge_tx: mpsc::Sender<()>) -> anyhow::Result<()> {
+        loop {
+            match self.connect_and_listen(&nudge_tx).await {
+                Ok(()) => tracing::warn!("State WebSocket closed cleanly, reconnecting"),
+                Err(err) => tracing::warn!(error = ?err, "State WebSocket failed, retrying"),
+            }
+            sleep(RECONNECT_BACKOFF).await;
+        }
+    }
+
+    /// The subscriber's WebSocket pings already keep the server
+    /// informed; nothing extra to send.
+    async fn heartbeat(&self, _panel_id: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
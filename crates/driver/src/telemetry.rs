@@ -46,7 +46,7 @@ pub struct Metrics {
 }
 
 impl Metrics {
-    fn new(meter: &Meter) -> Self {
+    pub(crate) fn new(meter: &Meter) -> Self {
         Self {
             heartbeat: meter
                 .u64_counter("led.driver.heartbeat")
@@ -0,0 +1,35 @@
+//! Fixture provider: current conditions come from a JSON file holding
+//! a serialized [`WeatherReport`], e.g.
+//!
+//! ```json
+//! { "condition": "Rain", "temperature": 11.5, "high": 14, "low": 8 }
+//! ```
+//!
+//! Re-read on every poll, so editing the file changes the panel.
+
+use std::path::PathBuf;
+
+use anyhow::Context;
+use display_core::weather::WeatherReport;
+
+use super::WeatherProvider;
+
+pub struct FixtureProvider {
+    path: PathBuf,
+}
+
+impl FixtureProvider {
+    #[must_use]
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+impl WeatherProvider for FixtureProvider {
+    async fn current(&self) -> anyhow::Result<WeatherReport> {
+        let raw = tokio::fs::read_to_string(&self.path)
+            .await
+            .with_context(|| format!("read {}", self.path.display()))?;
+        serde_json::from_str(&raw).with_context(|| format!("parse {}", self.path.display()))
+    }
+}
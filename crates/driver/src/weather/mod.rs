@@ -0,0 +1,112 @@
+//! Pluggable weather providers for weather mode.
+//!
+//! [`poll`] asks a [`WeatherProvider`] for current conditions on an
+//! interval and stores the latest [`WeatherReport`] alongside the
+//! other polled values in [`DataValues`], where the render loop picks
+//! it up. Which provider runs is picked by `[weather]` in
+//! `config.toml`:
+//!
+//! * [`OpenMeteoProvider`] — the Open-Meteo forecast API, or anything
+//!   serving the same shape.
+//! * [`FixtureProvider`] — a JSON report on disk, for tests and
+//!   offline demos.
+
+use std::future::Future;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use display_core::weather::WeatherReport;
+
+use crate::data::DataValues;
+
+pub mod fixture;
+pub mod open_meteo;
+
+pub use fixture::FixtureProvider;
+pub use open_meteo::OpenMeteoProvider;
+
+/// A backend current conditions can be fetched from.
+pub trait WeatherProvider: Send + Sync + 'static {
+    /// Fetch current conditions. One attempt; [`poll`] owns retry.
+    fn current(&self) -> impl Future<Output = anyhow::Result<WeatherReport>> + Send;
+}
+
+/// Provider selection, from a `[weather]` table:
+///
+/// ```toml
+/// [weather]
+/// kind = "open-meteo"
+/// latitude = 40.71
+/// longitude = -74.01
+/// ```
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum WeatherConfig {
+    OpenMeteo {
+        latitude: f64,
+        longitude: f64,
+        /// Forecast endpoint. Defaults to the public Open-Meteo API.
+        #[serde(default = "open_meteo::default_url")]
+        url: String,
+        #[serde(default = "default_open_meteo_interval_secs")]
+        interval_secs: u64,
+    },
+    /// A serialized [`WeatherReport`] on disk, re-read every
+    /// `interval_secs`.
+    Fixture {
+        path: PathBuf,
+        #[serde(default = "default_fixture_interval_secs")]
+        interval_secs: u64,
+    },
+}
+
+/// Forecasts change slowly and the public API is rate-limited.
+fn default_open_meteo_interval_secs() -> u64 {
+    900
+}
+
+fn default_fixture_interval_secs() -> u64 {
+    10
+}
+
+/// Run the provider `config` selects forever, storing each report in
+/// `values`.
+pub async fn run(config: WeatherConfig, values: DataValues) -> anyhow::Result<()> {
+    match config {
+        WeatherConfig::OpenMeteo {
+            latitude,
+            longitude,
+            url,
+            interval_secs,
+        } => {
+            let provider = OpenMeteoProvider::new(url, latitude, longitude)?;
+            poll(provider, Duration::from_secs(interval_secs), values).await
+        }
+        WeatherConfig::Fixture {
+            path,
+            interval_secs,
+        } => {
+            let provider = FixtureProvider::new(path);
+            poll(provider, Duration::from_secs(interval_secs), values).await
+        }
+    }
+}
+
+/// Ask `provider` for current conditions every `interval` forever. A
+/// failed fetch keeps the last report on the panel.
+pub async fn poll<P: WeatherProvider>(
+    provider: P,
+    interval: Duration,
+    values: DataValues,
+) -> anyhow::Result<()> {
+    let mut tick = tokio::time::interval(interval.max(Duration::from_secs(1)));
+    loop {
+        tick.tick().await;
+        match provider.current().await {
+            Ok(report) => values.write().weather = Some(report),
+            Err(err) => {
+                tracing::warn!(error = ?err, "weather fetch failed; keeping last report");
+            }
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//! Open-Meteo provider: current conditions from the free
+//! [Open-Meteo](https://open-meteo.com) forecast API, no key needed.
+//! One request fetches the current temperature, WMO weather code and
+//! day/night flag plus today's high and low.
+
+use std::time::Duration;
+
+use anyhow::Context;
+use display_core::weather::{WeatherCondition, WeatherReport};
+
+use super::WeatherProvider;
+
+/// Per-request cap so a hung API can't stall the poll loop.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub(super) fn default_url() -> String {
+    "https://api.open-meteo.com/v1/forecast".to_owned()
+}
+
+pub struct OpenMeteoProvider {
+    client: reqwest::Client,
+    url: String,
+    latitude: f64,
+    longitude: f64,
+}
+
+impl OpenMeteoProvider {
+    pub fn new(url: String, latitude: f64, longitude: f64) -> anyhow::Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .context("build weather client")?;
+        Ok(Self {
+            client,
+            url,
+            latitude,
+            longitude,
+        })
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct Forecast {
+    current: Current,
+    daily: Daily,
+}
+
+#[derive(serde::Deserialize)]
+struct Current {
+    temperature_2m: f32,
+    weather_code: u8,
+    is_day: u8,
+}
+
+#[derive(serde::Deserialize)]
+struct Daily {
+    temperature_2m_max: Vec<f32>,
+    temperature_2m_min: Vec<f32>,
+}
+
+impl WeatherProvider for OpenMeteoProvider {
+    async fn current(&self) -> anyhow::Result<WeatherReport> {
+        let body = self
+            .client
+            .get(&self.url)
+            .query(&[
+                ("latitude", self.latitude.to_string()),
+                ("longitude", self.longitude.to_string()),
+                ("current", "temperature_2m,weather_code,is_day".to_owned()),
+                ("daily", "temperature_2m_max,temperature_2m_min".to_owned()),
+                ("timezone", "auto".to_owned()),
+                ("forecast_days", "1".to_owned()),
+            ])
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        report(&body)
+    }
+}
+
+/// A forecast response body as our report.
+fn report(body: &str) -> anyhow::Result<WeatherReport> {
+    let forecast: Forecast = serde_json::from_str(body).context("parse forecast")?;
+    let current = forecast.current;
+    Ok(WeatherReport {
+        condition: condition(current.weather_code),
+        is_day: current.is_day != 0,
+        temperature: current.temperature_2m,
+        high: today(&forecast.daily.temperature_2m_max).unwrap_or(current.temperature_2m),
+        low: today(&forecast.daily.temperature_2m_min).unwrap_or(current.temperature_2m),
+    })
+}
+
+/// Today's entry of a daily series; `forecast_days=1` asks for just
+/// that one.
+fn today(values: &[f32]) -> Option<f32> {
+    values.first().copied()
+}
+
+/// Our icon for a WMO weather interpretation code.
+fn condition(code: u8) -> WeatherCondition {
+    match code {
+        0 => WeatherCondition::Clear,
+        1 | 2 => WeatherCondition::PartlyCloudy,
+        45 | 48 => WeatherCondition::Fog,
+        51..=57 => WeatherCondition::Drizzle,
+        61..=67 | 80..=82 => WeatherCondition::Rain,
+        71..=77 | 85 | 86 => WeatherCondition::Snow,
+        95..=99 => WeatherCondition::Thunderstorm,
+        _ => WeatherCondition::Cloudy,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A `forecast_days=1` response, as the API returns it.
+    const RESPONSE: &str = r#"{
+        "latitude": 51.5,
+        "longitude": -0.120000124,
+        "generationtime_ms": 0.0510215759277344,
+        "utc_offset_seconds": 3600,
+        "timezone": "Europe/London",
+        "timezone_abbreviation": "GMT+1",
+        "elevation": 23.0,
+        "current_units": {
+            "time": "iso8601",
+            "interval": "seconds",
+            "temperature_2m": "°C",
+            "weather_code": "wmo code",
+            "is_day": ""
+        },
+        "current": {
+            "time": "2026-06-14T21:45",
+            "interval": 900,
+            "temperature_2m": 17.3,
+            "weather_code": 61,
+            "is_day": 0
+        },
+        "daily_units": {
+            "time": "iso8601",
+            "temperature_2m_max": "°C",
+            "temperature_2m_min": "°C"
+        },
+        "daily": {
+            "time": ["2026-06-14"],
+            "temperature_2m_max": [21.8],
+            "temperature_2m_min": [12.4]
+        }
+    }"#;
+
+    #[test]
+    fn parses_a_forecast_response() {
+        assert_eq!(
+            report(RESPONSE).unwrap(),
+            WeatherReport {
+                condition: WeatherCondition::Rain,
+                is_day: false,
+                temperature: 17.3,
+                high: 21.8,
+                low: 12.4,
+            }
+        );
+    }
+
+    #[test]
+    fn missing_daily_values_fall_back_to_now() {
+        let body = RESPONSE.replace("[21.8]", "[]").replace("[12.4]", "[]");
+        let parsed = report(&body).unwrap();
+        assert!((parsed.high - 17.3).abs() < f32::EPSILON);
+        assert!((parsed.low - 17.3).abs() < f32::EPSILON);
+        assert!(report("{}").is_err());
+    }
+
+    #[test]
+    fn wmo_codes_map_to_icons() {
+        use WeatherCondition::*;
+        let table = [
+            (0, Clear),
+            (1, PartlyCloudy),
+            (2, PartlyCloudy),
+            (3, Cloudy),
+            (45, Fog),
+            (48, Fog),
+            (51, Drizzle),
+            (53, Drizzle),
+            (55, Drizzle),
+            (56, Drizzle),
+            (57, Drizzle),
+            (61, Rain),
+            (63, Rain),
+            (65, Rain),
+            (66, Rain),
+            (67, Rain),
+            (71, Snow),
+            (73, Snow),
+            (75, Snow),
+            (77, Snow),
+            (80, Rain),
+            (81, Rain),
+            (82, Rain),
+            (85, Snow),
+            (86, Snow),
+            (95, Thunderstorm),
+            (96, Thunderstorm),
+            (99, Thunderstorm),
+            (42, Cloudy),
+        ];
+        for (code, expected) in table {
+            assert_eq!(condition(code), expected, "WMO code {code}");
+        }
+    }
+}
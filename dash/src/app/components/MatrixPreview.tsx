@@ -31,6 +31,11 @@ type Scene = {
     is_paused: boolean;
     is_off: boolean;
     brightness: number;
+    brightness_curve: "Linear" | "Perceptual";
+    dither: boolean;
+    rotation: number;
+    flip_horizontal: boolean;
+    flip_vertical: boolean;
     flash: { is_active: boolean; on_steps: number; total_steps: number };
   };
 };
@@ -168,6 +173,11 @@ export function MatrixPreview({
   isPaused = false,
   isOff = false,
   brightness = 1,
+  brightnessCurve = "Linear",
+  dither = false,
+  rotation = 0,
+  flipHorizontal = false,
+  flipVertical = false,
 }: {
   mode: Mode;
   offline?: boolean;
@@ -179,6 +189,15 @@ export function MatrixPreview({
   isOff?: boolean;
   /** Final 0–1 brightness multiplier, mirrors the Pi driver. */
   brightness?: number;
+  /** How brightness dims, and whether to dither the dimmed levels —
+   * mirrors the Pi driver. */
+  brightnessCurve?: "Linear" | "Perceptual";
+  dither?: boolean;
+  /** Clockwise degrees plus mirroring, mirrors the Pi driver so the
+   * preview shows the panel as mounted. */
+  rotation?: number;
+  flipHorizontal?: boolean;
+  flipVertical?: boolean;
 }) {
   const panelId = useContext(PanelContext);
   const entriesData = entriesActions.get.useSWR(panelId);
@@ -260,9 +279,31 @@ export function MatrixPreview({
       : mode;
     return {
       mode: expanded,
-      panel: { is_paused: isPaused, is_off: isOff, brightness, flash: FLASH_OFF },
+      panel: {
+        is_paused: isPaused,
+        is_off: isOff,
+        brightness,
+        brightness_curve: brightnessCurve,
+        dither,
+        rotation,
+        flip_horizontal: flipHorizontal,
+        flip_vertical: flipVertical,
+        flash: FLASH_OFF,
+      },
     };
-  }, [items, mode, scroll, isPaused, isOff, brightness]);
+  }, [
+    items,
+    mode,
+    scroll,
+    isPaused,
+    isOff,
+    brightness,
+    brightnessCurve,
+    dither,
+    rotation,
+    flipHorizontal,
+    flipVertical,
+  ]);
 
   // Whether the active mode produces motion. Static modes (image,
   // test) and an empty/blank text panel never advance, so once their
@@ -617,7 +658,7 @@ export function MatrixPreview({
 // push effect. Everything else is fully captured by this key.
 function structuralKey(frame: Scene): string {
   const { panel, mode } = frame;
-  const p = `${panel.is_paused ? 1 : 0}${panel.is_off ? 1 : 0}b${panel.brightness}`;
+  const p = `${panel.is_paused ? 1 : 0}${panel.is_off ? 1 : 0}b${panel.brightness}${panel.brightness_curve}${panel.dither ? 1 : 0}r${panel.rotation}${panel.flip_horizontal ? 1 : 0}${panel.flip_vertical ? 1 : 0}`;
   if ("Text" in mode) {
     const t = mode.Text;
     const entries = t.entries
@@ -187,7 +187,7 @@ const ShapesConfig = z
 
 const TestConfig = z
   .object({
-    pattern: z.enum(["ColorBars", "Gradient", "Checkerboard"]).optional(),
+    pattern: z.enum(["ColorBars", "Gradient", "Checkerboard", "Calibration"]).optional(),
   })
   .optional();
 
@@ -332,7 +332,7 @@ const handler = createMcpHandler(
           },
           test: {
             description: "Static diagnostic patterns.",
-            schema: { pattern: "'ColorBars' | 'Gradient' | 'Checkerboard'" },
+            schema: { pattern: "'ColorBars' | 'Gradient' | 'Checkerboard' | 'Calibration'" },
             defaults: DEFAULT_TEST_CONFIG,
           },
           image: {
@@ -353,6 +353,13 @@ export default function Page() {
                   isPaused={activePanel?.is_paused ?? false}
                   isOff={activePanel?.is_off ?? false}
                   brightness={activePanel?.brightness ?? 1}
+                  brightnessCurve={
+                    activePanel?.brightness_curve === "Perceptual" ? "Perceptual" : "Linear"
+                  }
+                  dither={activePanel?.dither ?? false}
+                  rotation={activePanel?.rotation ?? 0}
+                  flipHorizontal={activePanel?.flip_horizontal ?? false}
+                  flipVertical={activePanel?.flip_vertical ?? false}
                 />
               ) : (
                 // No panels registered — an offline simulator here would
@@ -16,6 +16,7 @@ const PATTERNS: { id: TestPatternId; label: string; blurb: string }[] = [
   { id: "ColorBars",    label: "color bars",    blurb: "RGB primaries + corner pixels for geometry" },
   { id: "Gradient",     label: "gradient",      blurb: "horizontal R/G/B brightness ramps" },
   { id: "Checkerboard", label: "checkerboard",  blurb: "1×1 checker — surfaces moiré + row-driver shadows" },
+  { id: "Calibration",  label: "calibration",   blurb: "grey steps, near-black steps + orange swatch for tuning color" },
 ];
 
 export function parseTestConfig(raw: unknown): TestSceneConfig {
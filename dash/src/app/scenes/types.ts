@@ -273,7 +273,7 @@ export const SHAPE_KINDS: readonly ShapeKind[] = [
  * Test/diagnostic patterns. Render-only — no animation, no per-frame
  * state. Mirrors `display_core::test::TestPattern` + `TestScene`.
  */
-export type TestPatternId = "ColorBars" | "Gradient" | "Checkerboard";
+export type TestPatternId = "ColorBars" | "Gradient" | "Checkerboard" | "Calibration";
 
 export type TestScene = {
   pattern: TestPatternId;
@@ -295,6 +295,7 @@ export const TEST_PATTERNS: readonly TestPatternId[] = [
   "ColorBars",
   "Gradient",
   "Checkerboard",
+  "Calibration",
 ];
 
 export type ModeMeta = {
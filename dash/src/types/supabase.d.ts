@@ -44,10 +44,15 @@ export type Database = {
       panels: {
         Row: {
           brightness: number;
+          brightness_curve: string;
+          calibration: Json;
           created_at: string;
           description: string;
+          dither: boolean;
           driver_version: string | null;
           flash: Json;
+          flip_horizontal: boolean;
+          flip_vertical: boolean;
           id: string;
           is_off: boolean;
           is_paused: boolean;
@@ -56,14 +61,22 @@ export type Database = {
           mode: string;
           mode_config: Json;
           name: string;
+          rotation: number;
+          schedule: Json;
           scroll: number;
+          transition: Json;
         };
         Insert: {
           brightness?: number;
+          brightness_curve?: string;
+          calibration?: Json;
           created_at?: string;
           description?: string;
+          dither?: boolean;
           driver_version?: string | null;
           flash: Json;
+          flip_horizontal?: boolean;
+          flip_vertical?: boolean;
           id?: string;
           is_off?: boolean;
           is_paused?: boolean;
@@ -72,14 +85,22 @@ export type Database = {
           mode?: string;
           mode_config?: Json;
           name?: string;
+          rotation?: number;
+          schedule?: Json;
           scroll?: number;
+          transition?: Json;
         };
         Update: {
           brightness?: number;
+          brightness_curve?: string;
+          calibration?: Json;
           created_at?: string;
           description?: string;
+          dither?: boolean;
           driver_version?: string | null;
           flash?: Json;
+          flip_horizontal?: boolean;
+          flip_vertical?: boolean;
           id?: string;
           is_off?: boolean;
           is_paused?: boolean;
@@ -88,7 +109,10 @@ export type Database = {
           mode?: string;
           mode_config?: Json;
           name?: string;
+          rotation?: number;
+          schedule?: Json;
           scroll?: number;
+          transition?: Json;
         };
         Relationships: [];
       };
@@ -19,8 +19,9 @@ load_tofu_outputs
 
 mkdir -p dev/log
 rendered="dev/config.toml"
-# OTel force-disabled in dev (override any inherited value).
-OTEL_ENDPOINT="" OTEL_AUTHORIZATION="" \
+# OTel force-disabled in dev (override any inherited value). State
+# cache lands next to the logs instead of /var/lib.
+OTEL_ENDPOINT="" OTEL_AUTHORIZATION="" STATE_DIR="dev/" \
     render_config_toml "dev" "dev/log/" "$rendered"
 
 cargo run -p led-driver --no-default-features -- \
@@ -45,9 +45,9 @@ load_tofu_outputs() {
 #   $2 — log dir (e.g. /var/log/led/ for Pi, dev/log/ for dev recipe)
 #   $3 — output path
 # Reads SUPABASE_URL, SUPABASE_ANON_KEY, OTEL_ENDPOINT, OTEL_AUTHORIZATION,
-# COLOR_ORDER from the env. Empty OTel envs render to empty strings
-# (driver treats empty endpoint as "OTel disabled"); COLOR_ORDER
-# defaults to RGB.
+# COLOR_ORDER, STATE_DIR from the env. Empty OTel envs render to empty
+# strings (driver treats empty endpoint as "OTel disabled"); COLOR_ORDER
+# defaults to RGB, STATE_DIR to /var/lib/led/.
 render_config_toml() {
     local panel_id="$1"
     local log_dir="$2"
@@ -60,6 +60,7 @@ render_config_toml() {
         -e "s|@@OTEL_ENDPOINT@@|${OTEL_ENDPOINT:-}|g" \
         -e "s|@@OTEL_AUTHORIZATION@@|${OTEL_AUTHORIZATION:-}|g" \
         -e "s|@@COLOR_ORDER@@|${COLOR_ORDER:-RGB}|g" \
+        -e "s|@@STATE_DIR@@|${STATE_DIR:-/var/lib/led/}|g" \
         "$REPO_ROOT/service/config.toml.tmpl" > "$out"
 }
 
@@ -0,0 +1,11 @@
+-- Time-of-day schedule. A list of weekly windows, each of which can
+-- force the panel off/on, override brightness, or switch mode while
+-- it's active; the driver evaluates it every frame in the schedule's
+-- timezone. Empty (the default) leaves the panel under manual control.
+--
+-- Shape: { "timezone": "Europe/London" | null,
+--          "windows": [{ "days": ["sat", "sun"], "start": "22:00",
+--                        "end": "07:00", "is_off": true }, …] }
+
+alter table public.panels
+    add column if not exists schedule jsonb not null default '{}'::jsonb;
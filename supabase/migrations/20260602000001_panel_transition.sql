@@ -0,0 +1,8 @@
+-- Scene transition. How the driver blends into new content whenever
+-- the panel's mode or last_updated changes: { "kind": "Crossfade" |
+-- "WipeHorizontal" | "WipeVertical" | "SlideHorizontal" |
+-- "SlideVertical" | "Dissolve" | "Cut", "steps": <frames> }. Empty
+-- (the default) is a cut; a kind without steps runs 30 frames.
+
+alter table public.panels
+    add column if not exists transition jsonb not null default '{}'::jsonb;
@@ -0,0 +1,11 @@
+-- Panel orientation, for panels mounted upside-down or in portrait.
+-- A final transform the driver (and the WASM simulator) applies to the
+-- whole frame: `rotation` is clockwise degrees (0 / 90 / 180 / 270),
+-- then the frame is mirrored left-right / top-bottom by the flips.
+-- Renderers lay out on the rotated size.
+
+alter table public.panels
+    add column if not exists rotation smallint not null default 0
+        check (rotation in (0, 90, 180, 270)),
+    add column if not exists flip_horizontal boolean not null default false,
+    add column if not exists flip_vertical boolean not null default false;
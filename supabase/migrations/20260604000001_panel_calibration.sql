@@ -0,0 +1,10 @@
+-- Per-panel color calibration overrides. The driver corrects each
+-- frame with its config.toml `[calibration]` table; keys set here
+-- replace that table's, key by key:
+--   { "gain": { "r": 1, "g": 0.85, "b": 0.7 } | <number>,
+--     "gamma": { "r": 2.2, "g": 2.2, "b": 2.2 } | <number>,
+--     "black_level": <0-255> }
+-- Empty (the default) keeps the Pi's own calibration.
+
+alter table public.panels
+    add column if not exists calibration jsonb not null default '{}'::jsonb;
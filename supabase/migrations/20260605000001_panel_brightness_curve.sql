@@ -0,0 +1,10 @@
+-- How brightness dims the panel. `brightness_curve` is 'Linear' (every
+-- channel value times brightness) or 'Perceptual' (brightness is the
+-- fraction of light given off, gamma-aware, so dim colors stay lit).
+-- `dither` spreads the in-between levels across neighbouring pixels
+-- and frames, so dim scenes keep their gradients instead of banding.
+
+alter table public.panels
+    add column if not exists brightness_curve text not null default 'Linear'
+        check (brightness_curve in ('Linear', 'Perceptual')),
+    add column if not exists dither boolean not null default false;